@@ -0,0 +1,68 @@
+use std::{env, fs, path::Path, process::Command};
+
+const CORE_PACKAGE: &'static str = "name = \"rust_db_manager_core\"";
+
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let lock = Path::new(&root).join("Cargo.lock");
+
+    println!("cargo:rustc-env=RUST_DB_MANAGER_GIT_COMMIT={}", git_commit());
+    println!("cargo:rustc-env=RUST_DB_MANAGER_CORE_VERSION={}", core_version(&lock));
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    for path in git_watched(&root) {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+}
+
+// HEAD only changes when switching branches, commits move the branch ref,
+// which may also live packed in packed-refs.
+fn git_watched(root: &str) -> Vec<String> {
+    let git = Path::new(root).join(".git");
+    let head = git.join("HEAD");
+
+    let mut paths = Vec::from(vec![head.clone(), git.join("packed-refs")]);
+
+    let content = fs::read_to_string(&head).unwrap_or_default();
+    if let Some(reference) = content.trim().strip_prefix("ref:") {
+        paths.push(git.join(reference.trim()));
+    }
+
+    paths
+        .into_iter()
+        .filter(|path| path.exists())
+        .map(|path| path.display().to_string())
+        .collect::<Vec<String>>()
+}
+
+fn git_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| String::from(commit.trim()))
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or(String::from("unknown"))
+}
+
+fn core_version(lock: &Path) -> String {
+    let content = fs::read_to_string(lock).unwrap_or_default();
+    let package = match content.split("[[package]]").find(|package| package.contains(CORE_PACKAGE)) {
+        Some(package) => package,
+        None => return String::from("unknown"),
+    };
+
+    let field = |key: &str| {
+        package
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(key))
+            .map(|value| String::from(value.trim().trim_matches('"')))
+    };
+
+    let version = field("version =").unwrap_or(String::from("unknown"));
+    match field("source =").and_then(|source| source.split_once('#').map(|(_, commit)| String::from(commit))) {
+        Some(commit) => format!("{} ({})", version, &commit[..commit.len().min(7)]),
+        None => version,
+    }
+}
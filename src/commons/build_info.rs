@@ -0,0 +1,28 @@
+pub struct BuildInfo {
+}
+
+impl BuildInfo {
+
+    pub fn version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    pub fn commit() -> &'static str {
+        env!("RUST_DB_MANAGER_GIT_COMMIT")
+    }
+
+    pub fn core_version() -> &'static str {
+        env!("RUST_DB_MANAGER_CORE_VERSION")
+    }
+
+    pub fn summary() -> String {
+        format!(
+            "{} {} (commit {}), built against rust_db_manager_core {}",
+            env!("CARGO_PKG_NAME"),
+            BuildInfo::version(),
+            BuildInfo::commit(),
+            BuildInfo::core_version()
+        )
+    }
+
+}
@@ -0,0 +1,57 @@
+use std::env;
+
+use super::terminal_configuration::{self, TerminalConfiguration};
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum EColorLevel {
+    None,
+    Basic,
+    Ansi256,
+    TrueColor,
+}
+
+impl EColorLevel {
+
+    pub fn detect() -> EColorLevel {
+        if let Some(level) = TerminalConfiguration::variable(terminal_configuration::COLOR) {
+            if let Some(level) = EColorLevel::from_str(&level) {
+                return level;
+            }
+        }
+
+        if TerminalConfiguration::variable("NO_COLOR").is_some() {
+            return EColorLevel::None;
+        }
+
+        let term = env::var("TERM").unwrap_or_default().to_lowercase();
+        if term.is_empty() || term == "dumb" {
+            return EColorLevel::None;
+        }
+
+        let color_term = env::var("COLORTERM").unwrap_or_default().to_lowercase();
+        if color_term == "truecolor" || color_term == "24bit" {
+            return EColorLevel::TrueColor;
+        }
+
+        if term.contains("256color") {
+            return EColorLevel::Ansi256;
+        }
+
+        EColorLevel::Basic
+    }
+
+    pub fn from_str(level: &str) -> Option<EColorLevel> {
+        match level.trim().to_lowercase().as_str() {
+            "none" | "never" | "off" => Some(EColorLevel::None),
+            "16" | "basic" => Some(EColorLevel::Basic),
+            "256" => Some(EColorLevel::Ansi256),
+            "truecolor" | "24bit" => Some(EColorLevel::TrueColor),
+            _ => None,
+        }
+    }
+
+    pub fn is_colored(&self) -> bool {
+        *self != EColorLevel::None
+    }
+
+}
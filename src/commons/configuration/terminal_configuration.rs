@@ -0,0 +1,102 @@
+use std::env;
+
+pub const PROTECTED_HOSTS: &'static str = "RUST_DB_MANAGER_PROTECTED_HOSTS";
+pub const COLOR: &'static str = "RUST_DB_MANAGER_COLOR";
+pub const REPEAT_LAST: &'static str = "RUST_DB_MANAGER_REPEAT_LAST";
+pub const HOST: &'static str = "RUST_DB_MANAGER_HOST";
+pub const PORT: &'static str = "RUST_DB_MANAGER_PORT";
+pub const THOUSANDS_SEPARATOR: &'static str = "RUST_DB_MANAGER_THOUSANDS_SEPARATOR";
+pub const LABEL_FIELDS: &'static str = "RUST_DB_MANAGER_LABEL_FIELDS";
+pub const LATEST_COUNT: &'static str = "RUST_DB_MANAGER_LATEST_COUNT";
+pub const MENU_MODE: &'static str = "RUST_DB_MANAGER_MENU_MODE";
+pub const SAVED_QUERIES: &'static str = "RUST_DB_MANAGER_SAVED_QUERIES";
+pub const NONE_POSITION: &'static str = "RUST_DB_MANAGER_NONE_POSITION";
+pub const SSH_HOST: &'static str = "RUST_DB_MANAGER_SSH_HOST";
+pub const SSH_USER: &'static str = "RUST_DB_MANAGER_SSH_USER";
+pub const SSH_KEY: &'static str = "RUST_DB_MANAGER_SSH_KEY";
+pub const SSH_PORT: &'static str = "RUST_DB_MANAGER_SSH_PORT";
+
+pub struct TerminalConfiguration {
+}
+
+impl TerminalConfiguration {
+
+    pub fn keys() -> Vec<&'static str> {
+        Vec::from(vec![HOST, PORT, PROTECTED_HOSTS, COLOR, REPEAT_LAST, THOUSANDS_SEPARATOR, LABEL_FIELDS, LATEST_COUNT, MENU_MODE, SAVED_QUERIES, NONE_POSITION, SSH_HOST, SSH_USER, SSH_KEY, SSH_PORT])
+    }
+
+    pub fn variable(key: &str) -> Option<String> {
+        let value = env::var(key).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        Some(String::from(value))
+    }
+
+    pub fn list(key: &str) -> Vec<String> {
+        match TerminalConfiguration::variable(key) {
+            Some(value) => value
+                .split(",")
+                .map(|v| String::from(v.trim()))
+                .filter(|v| !v.is_empty())
+                .collect::<Vec<String>>(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn flag(key: &str) -> bool {
+        match TerminalConfiguration::variable(key) {
+            Some(value) => matches!(value.to_lowercase().as_str(), "1" | "true" | "yes" | "on"),
+            None => false,
+        }
+    }
+
+    pub fn thousands_separator() -> String {
+        match TerminalConfiguration::variable(THOUSANDS_SEPARATOR) {
+            Some(value) if value.eq_ignore_ascii_case("none") => String::new(),
+            Some(value) => value,
+            None => String::from(","),
+        }
+    }
+
+    pub fn is_advanced_menu() -> bool {
+        match TerminalConfiguration::variable(MENU_MODE) {
+            Some(value) => value.eq_ignore_ascii_case("advanced"),
+            None => false,
+        }
+    }
+
+    pub fn is_none_last() -> bool {
+        match TerminalConfiguration::variable(NONE_POSITION) {
+            Some(value) => value.eq_ignore_ascii_case("bottom"),
+            None => false,
+        }
+    }
+
+    pub fn protected_hosts() -> Vec<String> {
+        TerminalConfiguration::list(PROTECTED_HOSTS)
+    }
+
+    pub fn is_protected(uri: &str) -> bool {
+        let host = TerminalConfiguration::uri_host(uri);
+        TerminalConfiguration::protected_hosts()
+            .iter()
+            .any(|pattern| host.contains(pattern.as_str()))
+    }
+
+    fn uri_host(uri: &str) -> &str {
+        let mut host = uri;
+        if let Some(index) = host.find("://") {
+            host = &host[index + 3..];
+        }
+        if let Some(index) = host.find("/") {
+            host = &host[..index];
+        }
+        if let Some(index) = host.rfind("@") {
+            host = &host[index + 1..];
+        }
+        host
+    }
+
+}
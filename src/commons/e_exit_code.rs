@@ -0,0 +1,46 @@
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EExitCode {
+    Config,
+    Connection,
+    Runtime,
+    Query,
+    Tunnel,
+}
+
+impl EExitCode {
+    pub fn values() -> Vec<EExitCode> {
+        Vec::from(vec![
+            EExitCode::Config,
+            EExitCode::Connection,
+            EExitCode::Runtime,
+            EExitCode::Query,
+            EExitCode::Tunnel,
+        ])
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            EExitCode::Config => 2,
+            EExitCode::Connection => 3,
+            EExitCode::Runtime => 4,
+            EExitCode::Query => 5,
+            EExitCode::Tunnel => 6,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            EExitCode::Config => "Configuration error, the service cannot be defined.",
+            EExitCode::Connection => "Connection error, the service cannot be reached.",
+            EExitCode::Runtime => "Runtime error, the terminal stopped unexpectedly.",
+            EExitCode::Query => "Query error, the executed query failed.",
+            EExitCode::Tunnel => "Tunnel error, the SSH tunnel cannot be established.",
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from(self.code())
+    }
+}
@@ -0,0 +1,46 @@
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EOutputFormat {
+    Text,
+    Json,
+}
+
+impl EOutputFormat {
+
+    pub fn values() -> Vec<EOutputFormat> {
+        Vec::from(vec![
+            EOutputFormat::Text,
+            EOutputFormat::Json,
+        ])
+    }
+
+    pub fn from_str(format: &str) -> Option<EOutputFormat> {
+        match format.trim().to_lowercase().as_str() {
+            "text" => Some(EOutputFormat::Text),
+            "json" => Some(EOutputFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            EOutputFormat::Text => "text",
+            EOutputFormat::Json => "json",
+        }
+    }
+
+    pub fn render(&self, items: &[String]) -> String {
+        match self {
+            EOutputFormat::Text => items.join("\n"),
+            EOutputFormat::Json => {
+                let values = items
+                    .iter()
+                    .map(|item| serde_json::from_str::<Value>(item).unwrap_or(Value::from(item.clone())))
+                    .collect::<Vec<Value>>();
+                serde_json::to_string_pretty(&Value::Array(values)).unwrap_or_default()
+            }
+        }
+    }
+
+}
@@ -0,0 +1,65 @@
+use super::e_output_format::EOutputFormat;
+
+pub const EXEC: &'static str = "exec";
+
+pub const HELP: &'static str = "--help";
+pub const HELP_SHORT: &'static str = "-h";
+pub const VERSION: &'static str = "--version";
+pub const VERSION_SHORT: &'static str = "-V";
+pub const PASSWORD_FILE: &'static str = "--password-file";
+pub const PASSWORD_STDIN: &'static str = "--password-stdin";
+pub const FORMAT: &'static str = "--format";
+
+#[derive(Clone, Debug, Default)]
+pub struct LaunchArguments {
+    pub command: Option<String>,
+    pub query: Option<String>,
+    pub password_file: Option<String>,
+    pub sw_password_stdin: bool,
+    pub format: Option<EOutputFormat>,
+    pub sw_help: bool,
+    pub sw_version: bool,
+}
+
+impl LaunchArguments {
+
+    pub fn parse(args: Vec<String>) -> Result<LaunchArguments, String> {
+        let mut arguments = LaunchArguments::default();
+        let mut positionals = Vec::<String>::new();
+
+        let mut args = args.into_iter().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                HELP | HELP_SHORT => arguments.sw_help = true,
+                VERSION | VERSION_SHORT => arguments.sw_version = true,
+                PASSWORD_STDIN => arguments.sw_password_stdin = true,
+                PASSWORD_FILE => {
+                    let path = args.next().ok_or(format!("Missing path for '{}'.", PASSWORD_FILE))?;
+                    arguments.password_file = Some(path);
+                }
+                FORMAT => {
+                    let format = args.next().ok_or(format!("Missing format for '{}'.", FORMAT))?;
+                    let format = EOutputFormat::from_str(&format).ok_or(format!("Unknown format '{}'.", format))?;
+                    arguments.format = Some(format);
+                }
+                _ if arg.starts_with("-") => return Err(format!("Unknown option '{}'.", arg)),
+                _ => positionals.push(arg),
+            }
+        }
+
+        if arguments.password_file.is_some() && arguments.sw_password_stdin {
+            return Err(format!("'{}' and '{}' cannot be used together.", PASSWORD_FILE, PASSWORD_STDIN));
+        }
+
+        let mut positionals = positionals.into_iter();
+        arguments.command = positionals.next();
+        arguments.query = positionals.next();
+
+        if let Some(extra) = positionals.next() {
+            return Err(format!("Unexpected argument '{}'.", extra));
+        }
+
+        Ok(arguments)
+    }
+
+}
@@ -0,0 +1,81 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+};
+
+use crossterm::{
+    event::{read, Event, KeyCode, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+use super::launch_arguments::{self, LaunchArguments};
+
+pub struct PasswordReader {
+}
+
+impl PasswordReader {
+
+    pub fn read(arguments: &LaunchArguments) -> Result<Option<String>, String> {
+        if let Some(path) = &arguments.password_file {
+            let password = fs::read_to_string(path)
+                .map_err(|e| format!("Cannot read password file '{}': {}", path, e))?;
+            return Ok(Some(PasswordReader::trim_line(&password)));
+        }
+
+        if !arguments.sw_password_stdin {
+            return Ok(None);
+        }
+
+        if io::stdin().is_terminal() {
+            return PasswordReader::read_hidden().map(Some);
+        }
+
+        let mut password = String::new();
+        io::stdin()
+            .read_line(&mut password)
+            .map_err(|e| format!("Cannot read password from '{}': {}", launch_arguments::PASSWORD_STDIN, e))?;
+
+        Ok(Some(PasswordReader::trim_line(&password)))
+    }
+
+    fn read_hidden() -> Result<String, String> {
+        eprint!("Password: ");
+        let _ = io::stderr().flush();
+
+        terminal::enable_raw_mode().map_err(|e| format!("Cannot read password: {}", e))?;
+        let result = PasswordReader::read_keys();
+        let _ = terminal::disable_raw_mode();
+
+        eprintln!();
+        result
+    }
+
+    fn read_keys() -> Result<String, String> {
+        let mut password = String::new();
+        loop {
+            let event = read().map_err(|e| format!("Cannot read password: {}", e))?;
+            let key_event = match event {
+                Event::Key(event) if event.kind == KeyEventKind::Press => event,
+                _ => continue,
+            };
+
+            match key_event.code {
+                KeyCode::Enter => return Ok(password),
+                KeyCode::Backspace => {
+                    password.pop();
+                }
+                KeyCode::Esc => return Err(String::from("Password input cancelled.")),
+                KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(String::from("Password input cancelled."));
+                }
+                KeyCode::Char(character) => password.push(character),
+                _ => {}
+            }
+        }
+    }
+
+    fn trim_line(password: &str) -> String {
+        String::from(password.trim_end_matches(|c| c == '\r' || c == '\n'))
+    }
+
+}
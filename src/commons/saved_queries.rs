@@ -0,0 +1,52 @@
+use std::{collections::BTreeMap, fs};
+
+use super::configuration::terminal_configuration::{self, TerminalConfiguration};
+
+const SEPARATOR: char = '\t';
+
+pub struct SavedQueries {
+}
+
+impl SavedQueries {
+
+    pub fn path() -> Option<String> {
+        TerminalConfiguration::variable(terminal_configuration::SAVED_QUERIES)
+    }
+
+    // One 'name<TAB>query' pair per line.
+    pub fn load() -> BTreeMap<String, String> {
+        let mut queries = BTreeMap::new();
+
+        let path = match SavedQueries::path() {
+            Some(path) => path,
+            None => return queries,
+        };
+
+        let content = fs::read_to_string(path).unwrap_or_default();
+        for line in content.lines() {
+            if let Some((name, query)) = line.split_once(SEPARATOR) {
+                queries.insert(String::from(name.trim()), String::from(query.trim()));
+            }
+        }
+
+        queries
+    }
+
+    pub fn store(queries: &BTreeMap<String, String>) -> Result<bool, String> {
+        let path = match SavedQueries::path() {
+            Some(path) => path,
+            None => return Ok(false),
+        };
+
+        let content = queries
+            .iter()
+            .map(|(name, query)| format!("{}{}{}\n", name, SEPARATOR, query))
+            .collect::<String>();
+
+        fs::write(&path, content)
+            .map_err(|e| format!("Cannot write saved queries to '{}': {}", path, e))?;
+
+        Ok(true)
+    }
+
+}
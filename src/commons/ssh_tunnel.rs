@@ -0,0 +1,103 @@
+use std::{
+    net::{TcpListener, TcpStream},
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::configuration::terminal_configuration::{self, TerminalConfiguration};
+
+const LOCAL_HOST: &'static str = "127.0.0.1";
+const SETUP_TIMEOUT: Duration = Duration::from_secs(10);
+const SETUP_POLL: Duration = Duration::from_millis(100);
+
+pub struct SshTunnel {
+    child: Child,
+    local_port: u16,
+}
+
+impl SshTunnel {
+
+    pub fn open(target_host: &str, target_port: u16) -> Result<Option<SshTunnel>, String> {
+        let host = match TerminalConfiguration::variable(terminal_configuration::SSH_HOST) {
+            Some(host) => host,
+            None => return Ok(None),
+        };
+
+        let local_port = SshTunnel::free_port()?;
+
+        let mut command = Command::new("ssh");
+        command
+            .arg("-N")
+            .args(["-o", "ExitOnForwardFailure=yes"])
+            .args(["-o", "BatchMode=yes"])
+            .arg("-L")
+            .arg(format!("{}:{}:{}:{}", LOCAL_HOST, local_port, target_host, target_port));
+
+        if let Some(user) = TerminalConfiguration::variable(terminal_configuration::SSH_USER) {
+            command.args(["-l", &user]);
+        }
+        if let Some(key) = TerminalConfiguration::variable(terminal_configuration::SSH_KEY) {
+            command.args(["-i", &key]);
+        }
+        if let Some(port) = TerminalConfiguration::variable(terminal_configuration::SSH_PORT) {
+            command.args(["-p", &port]);
+        }
+
+        let child = command
+            .arg(&host)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Cannot start ssh for '{}': {}", host, e))?;
+
+        let mut tunnel = SshTunnel { child, local_port };
+        tunnel.wait_ready(&host)?;
+
+        Ok(Some(tunnel))
+    }
+
+    pub fn local_host(&self) -> &'static str {
+        LOCAL_HOST
+    }
+
+    pub fn local_port(&self) -> u16 {
+        self.local_port
+    }
+
+    fn free_port() -> Result<u16, String> {
+        let listener = TcpListener::bind((LOCAL_HOST, 0))
+            .map_err(|e| format!("Cannot reserve a local port for the tunnel: {}", e))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| format!("Cannot reserve a local port for the tunnel: {}", e))?
+            .port();
+        Ok(port)
+    }
+
+    fn wait_ready(&mut self, host: &str) -> Result<(), String> {
+        let start = Instant::now();
+        while start.elapsed() < SETUP_TIMEOUT {
+            if let Ok(Some(status)) = self.child.try_wait() {
+                return Err(format!("SSH tunnel through '{}' closed: {}", host, status));
+            }
+            if TcpStream::connect((LOCAL_HOST, self.local_port)).is_ok() {
+                return Ok(());
+            }
+            thread::sleep(SETUP_POLL);
+        }
+
+        Err(format!("SSH tunnel through '{}' timed out after {} seconds.", host, SETUP_TIMEOUT.as_secs()))
+    }
+
+}
+
+impl Drop for SshTunnel {
+
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+
+}
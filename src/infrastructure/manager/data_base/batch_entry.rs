@@ -0,0 +1,9 @@
+#[derive(Clone, Debug)]
+pub struct BatchEntry {
+    pub label: String,
+    pub option: String,
+    pub args: Vec<String>,
+    pub data_base: Option<String>,
+    pub collection: Option<String>,
+    pub element: Option<Vec<String>>,
+}
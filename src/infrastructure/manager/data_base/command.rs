@@ -0,0 +1,158 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ECommandCategory {
+    Navigation,
+    Read,
+    Write,
+    Admin,
+    Config,
+}
+
+impl ECommandCategory {
+    pub fn values() -> Vec<ECommandCategory> {
+        Vec::from(vec![
+            ECommandCategory::Navigation,
+            ECommandCategory::Read,
+            ECommandCategory::Write,
+            ECommandCategory::Admin,
+            ECommandCategory::Config,
+        ])
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ECommandCategory::Navigation => "Navigation",
+            ECommandCategory::Read => "Read",
+            ECommandCategory::Write => "Write",
+            ECommandCategory::Admin => "Admin",
+            ECommandCategory::Config => "Config",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum ESelectionLevel {
+    None,
+    DataBase,
+    Collection,
+    Element,
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    key: &'static str,
+    label: &'static str,
+    description: &'static str,
+    category: ECommandCategory,
+    selection: ESelectionLevel,
+    destructive: bool,
+    require_input: bool,
+    menu: bool,
+    basic: bool,
+    wipe: bool,
+    read: bool,
+    unsupported: Option<&'static str>,
+}
+
+impl Command {
+
+    pub fn new(key: &'static str, label: &'static str, description: &'static str, category: ECommandCategory, selection: ESelectionLevel) -> Command {
+        Command {
+            key,
+            label,
+            description,
+            category,
+            selection,
+            destructive: false,
+            require_input: false,
+            menu: true,
+            basic: false,
+            wipe: false,
+            read: false,
+            unsupported: None,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn category(&self) -> ECommandCategory {
+        self.category
+    }
+
+    pub fn selection(&self) -> ESelectionLevel {
+        self.selection
+    }
+
+    pub fn is_destructive(&self) -> bool {
+        self.destructive
+    }
+
+    pub fn input_required(&self) -> bool {
+        self.require_input
+    }
+
+    pub fn is_menu(&self) -> bool {
+        self.menu
+    }
+
+    pub fn is_basic(&self) -> bool {
+        self.basic
+    }
+
+    pub fn is_wipe(&self) -> bool {
+        self.wipe
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.read || self.category == ECommandCategory::Read
+    }
+
+    pub fn unsupported_reason(&self) -> Option<&'static str> {
+        self.unsupported
+    }
+
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+
+    pub fn require_input(mut self) -> Self {
+        self.require_input = true;
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.menu = false;
+        self
+    }
+
+    pub fn basic(mut self) -> Self {
+        self.basic = true;
+        self
+    }
+
+    pub fn wipe(mut self) -> Self {
+        self.wipe = true;
+        self
+    }
+
+    pub fn reads(mut self) -> Self {
+        self.read = true;
+        self
+    }
+
+    pub fn unsupported(mut self, reason: &'static str) -> Self {
+        self.unsupported = Some(reason);
+        self
+    }
+
+}
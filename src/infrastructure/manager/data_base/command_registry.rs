@@ -0,0 +1,97 @@
+use super::{
+    command::{Command, ECommandCategory, ESelectionLevel},
+    manager_database,
+};
+
+pub struct CommandRegistry {
+}
+
+impl CommandRegistry {
+
+    pub fn commands() -> Vec<Command> {
+        Vec::from(vec![
+            Command::new(manager_database::HOME, "Home", "Go back to the main menu.", ECommandCategory::Navigation, ESelectionLevel::None).hidden(),
+            Command::new(manager_database::TEXT_INPUT, "Query", "Navigate with a query path.", ECommandCategory::Navigation, ESelectionLevel::None).hidden().reads(),
+
+            Command::new(manager_database::SAVE_QUERY, "Save query", "Save a query path under a name, typed as '<name> <query path>'.", ECommandCategory::Navigation, ESelectionLevel::None).require_input(),
+            Command::new(manager_database::SHOW_SAVED, "Saved queries", "Run or delete a saved query.", ECommandCategory::Navigation, ESelectionLevel::None),
+            Command::new(manager_database::RUN_SAVED, "Run saved query", "Run a saved query by name.", ECommandCategory::Navigation, ESelectionLevel::None).require_input().hidden(),
+            Command::new(manager_database::DELETE_SAVED, "Delete saved query", "Delete a saved query by name.", ECommandCategory::Config, ESelectionLevel::None).require_input().hidden().wipe(),
+
+            Command::new(manager_database::STATUS, "Status", "Check the service status.", ECommandCategory::Admin, ESelectionLevel::None).basic(),
+            Command::new(manager_database::CREATE_DATABASE, "Create database", "Create a new data base.", ECommandCategory::Write, ESelectionLevel::None).require_input(),
+            Command::new(manager_database::SHOW_DATABASES, "Show databases", "List the data bases of the repository.", ECommandCategory::Read, ESelectionLevel::None).basic(),
+            Command::new(manager_database::DATABASES_BY_COUNT, "Databases by count", "List the data bases by document count, scanning every collection.", ECommandCategory::Read, ESelectionLevel::None),
+            Command::new(manager_database::SELECT_DATABASE_PANEL, "Select database", "Choose the working data base.", ECommandCategory::Navigation, ESelectionLevel::None).basic().reads(),
+            Command::new(manager_database::SELECT_DATABASE, "Select database", "Set the working data base.", ECommandCategory::Navigation, ESelectionLevel::None).hidden(),
+            Command::new(manager_database::SWITCH_DATABASE, "Switch database", "Cycle among the recently used data bases.", ECommandCategory::Navigation, ESelectionLevel::None).hidden(),
+            Command::new(manager_database::TOGGLE_DATABASE_RECENCY, "Sort databases by recency", "Toggle the data base panel order.", ECommandCategory::Config, ESelectionLevel::None),
+            Command::new(manager_database::CLEAR_DATABASE_HISTORY, "Clear database history", "Forget the data base access history.", ECommandCategory::Config, ESelectionLevel::None).wipe(),
+
+            Command::new(manager_database::DROP_DATABASE, "Drop database", "Drop the selected data base.", ECommandCategory::Write, ESelectionLevel::DataBase).destructive(),
+            Command::new(manager_database::SNAPSHOT_DATABASE, "Snapshot database", "Export every collection of the selected data base as ndjson files into a directory.", ECommandCategory::Read, ESelectionLevel::DataBase).require_input(),
+            Command::new(manager_database::SHOW_COLLECTIONS, "Show collections", "List the collections of the selected data base.", ECommandCategory::Read, ESelectionLevel::DataBase).basic(),
+            Command::new(manager_database::SCHEMA_DIFF, "Schema diff", "Compare the selected collection schema with '[data base >] collection'.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::CHECK_REFERENCES, "Check references", "Report values of 'field > [data base >] collection' without a matching document.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::COLLECTION_OPTIONS, "Collection options", "Show the creation options of the selected collection, such as capped or validator.", ECommandCategory::Read, ESelectionLevel::Collection).unsupported("it does not expose collection creation options."),
+            Command::new(manager_database::SELECT_COLLECTION_PANEL, "Select collection", "Choose the working collection.", ECommandCategory::Navigation, ESelectionLevel::DataBase).basic().reads(),
+            Command::new(manager_database::SELECT_COLLECTION, "Select collection", "Set the working collection.", ECommandCategory::Navigation, ESelectionLevel::DataBase).hidden(),
+
+            Command::new(manager_database::SHOW_ELEMENTS, "Show elements", "List the elements of the selected collection.", ECommandCategory::Read, ESelectionLevel::Collection).basic(),
+            Command::new(manager_database::TOGGLE_FULL_LISTING, "List full elements", "Toggle between lite and full documents when listing elements.", ECommandCategory::Config, ESelectionLevel::Collection),
+            Command::new(manager_database::FIND_PAGE, "Find page", "List a page of elements, typed as '<limit> [skip]', cut client-side from the full listing.", ECommandCategory::Read, ESelectionLevel::Collection).require_input().basic(),
+            Command::new(manager_database::LATEST, "Latest elements", "List the newest elements, typed as '[count] [field]', by '_id' by default.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::FULLTEXT_SEARCH, "Full-text search", "Find elements with any text field containing a term.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::GROUP_COUNT, "Group count", "Count the elements per distinct value of a field.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::CHECKPOINT, "Checkpoint", "Remember the content hash of every element in the collection.", ECommandCategory::Read, ESelectionLevel::Collection),
+            Command::new(manager_database::DIFF_CHECKPOINT, "Diff checkpoint", "List the elements added, removed or modified since the checkpoint.", ECommandCategory::Read, ESelectionLevel::Collection),
+            Command::new(manager_database::BENCH_QUERY, "Benchmark query", "Run the find over the current selection N times and report latency percentiles.", ECommandCategory::Read, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::WHICH_INDEX, "Which index", "Report the index the backend would pick for a typed filter.", ECommandCategory::Read, ESelectionLevel::Collection).unsupported("it exposes neither explain plans nor typed filters."),
+            Command::new(manager_database::SELECT_ELEMENTS_PANEL, "Select element", "Choose the working element.", ECommandCategory::Navigation, ESelectionLevel::Collection).basic().reads(),
+            Command::new(manager_database::SET_LABEL_FIELDS, "Set element labels", "Label elements in the selection panel with comma separated fields, empty to reset.", ECommandCategory::Config, ESelectionLevel::Collection).require_input(),
+            Command::new(manager_database::SELECT_ELEMENT, "Select element", "Set the working element.", ECommandCategory::Navigation, ESelectionLevel::Collection).hidden(),
+
+            Command::new(manager_database::MARK_ELEMENTS_PANEL, "Mark elements", "Mark or unmark elements for batch actions.", ECommandCategory::Navigation, ESelectionLevel::Collection).reads(),
+            Command::new(manager_database::MARK_ELEMENT, "Mark element", "Toggle the mark of an element.", ECommandCategory::Navigation, ESelectionLevel::Collection).hidden(),
+            Command::new(manager_database::MARKED_ACTIONS, "Marked actions", "Run an action over every marked element.", ECommandCategory::Navigation, ESelectionLevel::Collection),
+            Command::new(manager_database::MARKED_COPY, "Copy marked", "Copy the marked elements to the clipboard.", ECommandCategory::Read, ESelectionLevel::Collection).hidden(),
+            Command::new(manager_database::MARKED_EXPORT, "Export marked", "Export the marked elements to a file as ndjson.", ECommandCategory::Read, ESelectionLevel::Collection).require_input().hidden(),
+            Command::new(manager_database::CLEAR_MARKS, "Clear marks", "Forget every marked element.", ECommandCategory::Config, ESelectionLevel::Collection).hidden().wipe(),
+
+            Command::new(manager_database::SHOW_SELECTED, "Show selected", "Show the selected elements.", ECommandCategory::Read, ESelectionLevel::Element).basic(),
+            Command::new(manager_database::SELECTED_ITEM, "Show item", "Show one of the selected elements.", ECommandCategory::Read, ESelectionLevel::Element).hidden(),
+            Command::new(manager_database::EXPORT_FILTERED, "Export selected", "Export the elements matching the current selection as ndjson.", ECommandCategory::Read, ESelectionLevel::Element).require_input(),
+            Command::new(manager_database::TREE_VIEW, "Tree view", "Browse the selected elements as a collapsible tree.", ECommandCategory::Read, ESelectionLevel::Element).basic(),
+            Command::new(manager_database::TREE_TOGGLE, "Toggle tree node", "Expand or collapse a tree node.", ECommandCategory::Navigation, ESelectionLevel::Element).hidden(),
+            Command::new(manager_database::DOCUMENT_HISTORY, "Document history", "List the prior versions of the selected element to view or restore one.", ECommandCategory::Read, ESelectionLevel::Element).unsupported("it exposes no versioned or point-in-time reads."),
+            Command::new(manager_database::TOGGLE_COMPACT, "Compact selected items", "Toggle between browsing the selected items with a detail pane and a plain one line per item list.", ECommandCategory::Config, ESelectionLevel::Element),
+
+            Command::new(manager_database::START_BATCH, "Start batch", "Queue the next commands instead of running them.", ECommandCategory::Config, ESelectionLevel::None),
+            Command::new(manager_database::RUN_BATCH, "Run batch", "Run every queued command in order after one confirmation.", ECommandCategory::Config, ESelectionLevel::None),
+            Command::new(manager_database::CLEAR_BATCH, "Clear batch", "Drop the queued commands and leave batch mode.", ECommandCategory::Config, ESelectionLevel::None).wipe(),
+
+            Command::new(manager_database::CLEAR_CACHE, "Clear cache", "Drop the in-memory caches so data is fetched again.", ECommandCategory::Admin, ESelectionLevel::None),
+            Command::new(manager_database::COPY_CONNECTION, "Copy connection details", "Copy the connection as JSON with the password redacted.", ECommandCategory::Admin, ESelectionLevel::None),
+            Command::new(manager_database::LOCKS, "Locks", "Show the held locks and the operations waiting on them.", ECommandCategory::Admin, ESelectionLevel::None).unsupported("it does not expose the server status."),
+            Command::new(manager_database::CONFIG_PATH, "Show configuration", "Show where settings come from and their current values.", ECommandCategory::Config, ESelectionLevel::None),
+            Command::new(manager_database::RESET_STATS, "Reset session stats", "Clear the footer counters.", ECommandCategory::Config, ESelectionLevel::None).wipe(),
+            Command::new(manager_database::REDRAW, "Redraw screen", "Clear the screen and render it again.", ECommandCategory::Navigation, ESelectionLevel::None),
+            Command::new(manager_database::EXPORT_VIEW, "Export view", "Write the current screen as plain text to a file.", ECommandCategory::Admin, ESelectionLevel::None),
+            Command::new(manager_database::TOGGLE_MENU_MODE, "Show advanced commands", "Toggle between the basic and the full menu.", ECommandCategory::Config, ESelectionLevel::None).basic(),
+            Command::new(manager_database::VERSION, "Version", "Show the version and build metadata.", ECommandCategory::Admin, ESelectionLevel::None),
+            Command::new(manager_database::HELP, "Help", "Show this help.", ECommandCategory::Config, ESelectionLevel::None).basic(),
+        ])
+    }
+
+    pub fn find(key: &str) -> Option<Command> {
+        CommandRegistry::commands().into_iter().find(|c| c.key() == key)
+    }
+
+    pub fn category(category: ECommandCategory) -> Vec<Command> {
+        CommandRegistry::commands()
+            .into_iter()
+            .filter(|c| c.category() == category && c.is_menu())
+            .collect::<Vec<Command>>()
+    }
+
+}
@@ -1,6 +1,18 @@
-use std::vec;
+use std::{
+    cmp::Ordering,
+    hash::{DefaultHasher, Hash, Hasher},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs,
+    io,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+    vec,
+};
 
 use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use serde_json::{Map, Value};
 
 use rust_db_manager_core::{
     domain::{
@@ -11,33 +23,108 @@ use rust_db_manager_core::{
     service::service::Service,
 };
 
-use crate::infrastructure::manager::{
-    i_manager::IManager,
-    terminal_cursor::TerminalCursor,
-    terminal_manager::{self, TerminalManager},
-    terminal_option::TerminalOption,
+use crate::{
+    commons::{
+        build_info::BuildInfo,
+        configuration::terminal_configuration::{self, TerminalConfiguration},
+        connection_uri::ConnectionUri,
+        e_output_format::EOutputFormat,
+        saved_queries::SavedQueries,
+    },
+    infrastructure::manager::{
+        data_base::{
+            batch_entry::BatchEntry,
+            command::{Command, ECommandCategory, ESelectionLevel},
+            command_registry::CommandRegistry,
+            schema::Schema,
+            session_stats::SessionStats,
+            tree_view::TreeView,
+        },
+        i_manager::IManager,
+        terminal_cursor::TerminalCursor,
+        terminal_manager::{self, TerminalManager},
+        terminal_option::TerminalOption,
+    },
 };
 
-const HOME: &'static str = "HOME";
+pub const HOME: &'static str = "HOME";
 pub const STATUS: &'static str = "STATUS";
+pub const HELP: &'static str = "HELP";
+pub const VERSION: &'static str = "VERSION";
+pub const REDRAW: &'static str = "REDRAW";
+pub const EXPORT_VIEW: &'static str = "EXPORT_VIEW";
 
-const TEXT_INPUT: &'static str = "TEXT_INPUT";
+pub const TEXT_INPUT: &'static str = "TEXT_INPUT";
+pub const SAVE_QUERY: &'static str = "SAVE_QUERY";
+pub const SHOW_SAVED: &'static str = "SHOW_SAVED";
+pub const RUN_SAVED: &'static str = "RUN_SAVED";
+pub const DELETE_SAVED: &'static str = "DELETE_SAVED";
 
 pub const CREATE_DATABASE: &'static str = "CREATE_DATABASE";
 pub const DROP_DATABASE: &'static str = "DROP_DATABASE";
+pub const SNAPSHOT_DATABASE: &'static str = "SNAPSHOT_DATABASE";
 pub const SHOW_DATABASES: &'static str = "SHOW_DATABASES";
+pub const DATABASES_BY_COUNT: &'static str = "DATABASES_BY_COUNT";
 pub const SELECT_DATABASE_PANEL: &'static str = "SELECT_DATABASE_PANEL";
 pub const SELECT_DATABASE: &'static str = "SELECT_DATABASE";
+pub const TOGGLE_DATABASE_RECENCY: &'static str = "TOGGLE_DATABASE_RECENCY";
+pub const CLEAR_DATABASE_HISTORY: &'static str = "CLEAR_DATABASE_HISTORY";
+pub const SWITCH_DATABASE: &'static str = "SWITCH_DATABASE";
 
 pub const SHOW_COLLECTIONS: &'static str = "SHOW_COLLECTIONS";
+pub const SCHEMA_DIFF: &'static str = "SCHEMA_DIFF";
+pub const CHECK_REFERENCES: &'static str = "CHECK_REFERENCES";
+pub const COLLECTION_OPTIONS: &'static str = "COLLECTION_OPTIONS";
 pub const SELECT_COLLECTION_PANEL: &'static str = "SELECT_COLLECTION_PANEL";
 pub const SELECT_COLLECTION: &'static str = "SELECT_COLLECTION";
 
 pub const SHOW_ELEMENTS: &'static str = "SHOW_ELEMENTS";
+pub const FIND_PAGE: &'static str = "FIND_PAGE";
+pub const LATEST: &'static str = "LATEST";
+pub const FULLTEXT_SEARCH: &'static str = "FULLTEXT_SEARCH";
+pub const GROUP_COUNT: &'static str = "GROUP_COUNT";
+pub const CHECKPOINT: &'static str = "CHECKPOINT";
+pub const DIFF_CHECKPOINT: &'static str = "DIFF_CHECKPOINT";
+pub const TOGGLE_FULL_LISTING: &'static str = "TOGGLE_FULL_LISTING";
+pub const BENCH_QUERY: &'static str = "BENCH_QUERY";
+pub const WHICH_INDEX: &'static str = "WHICH_INDEX";
 pub const SELECT_ELEMENTS_PANEL: &'static str = "SELECT_ELEMENTS_PANEL";
 pub const SELECT_ELEMENT: &'static str = "SELECT_ELEMENT";
+pub const SET_LABEL_FIELDS: &'static str = "SET_LABEL_FIELDS";
+
+pub const MARK_ELEMENTS_PANEL: &'static str = "MARK_ELEMENTS_PANEL";
+pub const MARK_ELEMENT: &'static str = "MARK_ELEMENT";
+pub const MARKED_ACTIONS: &'static str = "MARKED_ACTIONS";
+pub const MARKED_COPY: &'static str = "MARKED_COPY";
+pub const MARKED_EXPORT: &'static str = "MARKED_EXPORT";
+pub const CLEAR_MARKS: &'static str = "CLEAR_MARKS";
 
 pub const SHOW_SELECTED: &'static str = "SHOW_SELECTED";
+pub const SELECTED_ITEM: &'static str = "SELECTED_ITEM";
+pub const EXPORT_FILTERED: &'static str = "EXPORT_FILTERED";
+pub const TOGGLE_COMPACT: &'static str = "TOGGLE_COMPACT";
+pub const DOCUMENT_HISTORY: &'static str = "DOCUMENT_HISTORY";
+pub const TREE_VIEW: &'static str = "TREE_VIEW";
+pub const TREE_TOGGLE: &'static str = "TREE_TOGGLE";
+
+pub const RESET_STATS: &'static str = "RESET_STATS";
+pub const CLEAR_CACHE: &'static str = "CLEAR_CACHE";
+pub const COPY_CONNECTION: &'static str = "COPY_CONNECTION";
+pub const LOCKS: &'static str = "LOCKS";
+pub const CONFIG_PATH: &'static str = "CONFIG_PATH";
+pub const TOGGLE_MENU_MODE: &'static str = "TOGGLE_MENU_MODE";
+
+pub const START_BATCH: &'static str = "START_BATCH";
+pub const RUN_BATCH: &'static str = "RUN_BATCH";
+pub const CLEAR_BATCH: &'static str = "CLEAR_BATCH";
+
+const STATS_CONCURRENCY: usize = 4;
+const QUICK_SWITCH_DEPTH: usize = 3;
+const BENCH_MAX_ITERATIONS: usize = 1000;
+const LATEST_COUNT: usize = 10;
+const GROUP_LIMIT: usize = 20;
+const MODIFIED_FIELDS: [&'static str; 6] = ["updatedAt", "updated_at", "modifiedAt", "modified_at", "lastModified", "last_modified"];
+const GROUP_BAR_WIDTH: usize = 30;
 
 #[derive(Clone)]
 pub struct ManagerDatabase<T: IDBRepository> {
@@ -45,6 +132,23 @@ pub struct ManagerDatabase<T: IDBRepository> {
     pub data_base: Option<String>,
     pub collection: Option<String>,
     pub element: Option<Vec<String>>,
+    pub data_base_history: HashMap<String, SystemTime>,
+    pub sw_recency_sort: bool,
+    pub stats: SessionStats,
+    pub sw_protected: bool,
+    pub preview_cache: Arc<Mutex<HashMap<String, String>>>,
+    pub sw_compact: bool,
+    pub connection: Option<ConnectionUri>,
+    pub tree: Option<TreeView>,
+    pub sw_full_listing: bool,
+    pub marked: BTreeSet<String>,
+    pub label_fields: Vec<String>,
+    pub page: Option<String>,
+    pub sw_advanced: bool,
+    pub saved_queries: BTreeMap<String, String>,
+    pub checkpoint: Option<(String, BTreeMap<String, u64>)>,
+    pub batch: Option<Vec<BatchEntry>>,
+    pub failure: Option<String>,
 }
 
 #[async_trait]
@@ -53,32 +157,199 @@ impl<T: IDBRepository> IManager for ManagerDatabase<T> {
         return TEXT_INPUT;
     }
 
+    fn redraw_option(&self) -> &str {
+        return REDRAW;
+    }
+
+    fn export_view_option(&self) -> &str {
+        return EXPORT_VIEW;
+    }
+
+    fn quick_switch_option(&self) -> &str {
+        return SWITCH_DATABASE;
+    }
+
+    fn footer(&self) -> String {
+        let mut footer = format!(
+            "{}Reads: {} | Writes: {} | Errors: {}{}",
+            terminal_manager::ANSI_BOLD,
+            self.group_digits(self.stats.reads()),
+            self.group_digits(self.stats.writes()),
+            self.group_digits(self.stats.errors()),
+            terminal_manager::ANSI_RESET
+        );
+
+        if let Some(batch) = &self.batch {
+            footer = format!(
+                "{} | {}Batch: {} queued{}",
+                footer,
+                terminal_manager::ANSI_COLOR_YELLOW,
+                self.group_digits(batch.len()),
+                terminal_manager::ANSI_RESET
+            );
+        }
+
+        if !self.marked.is_empty() {
+            footer = format!(
+                "{} | {}Marked: {}{}",
+                footer,
+                terminal_manager::ANSI_COLOR_CYAN,
+                self.group_digits(self.marked.len()),
+                terminal_manager::ANSI_RESET
+            );
+        }
+
+        if self.sw_protected {
+            footer = format!(
+                "{}{}[PRODUCTION]{}\n{}",
+                terminal_manager::ANSI_COLOR_RED,
+                terminal_manager::ANSI_BOLD,
+                terminal_manager::ANSI_RESET,
+                footer
+            );
+        }
+
+        footer
+    }
+
     async fn manage(&self, option: TerminalOption<Self>) -> TerminalCursor<Self>
     where
         Self: Sized,
     {
-        match option.option().as_str() {
+        let command = match CommandRegistry::find(&option.option()) {
+            Some(command) => command,
+            None => {
+                self.stats.error();
+                let message = format!("Unknown command '{}'.", option.option());
+                return self.failed(&message);
+            }
+        };
+
+        if let Some(reason) = command.unsupported_reason() {
+            let message = format!("'{}' is unsupported by the core: {}", command.label(), reason);
+            return self.failed(&message);
+        }
+
+        if let Err(error) = self.verify_selection(command.selection()) {
+            return self.failed(&error.message());
+        }
+
+        let mut manager = self.clone();
+        if let Err(error) = manager.verify_existence(command.selection()).await {
+            self.stats.error();
+            return manager.failed(&error.message());
+        }
+
+        if self.batch.is_some() && self.is_batchable(&option, &command) {
+            return manager.queue_batch(command.label(), option);
+        }
+
+        if command.is_wipe() && !self.is_confirmed(&option) {
+            let message = self.wipe_preview(command.key(), &option);
+            return self.confirm_panel(&message, option);
+        }
+
+        if self.sw_protected && command.is_destructive() && !self.is_confirmed(&option) {
+            let message = format!(
+                "{}{}[PRODUCTION]{} Confirm '{}' on a protected connection:",
+                terminal_manager::ANSI_COLOR_RED,
+                terminal_manager::ANSI_BOLD,
+                terminal_manager::ANSI_RESET,
+                command.label()
+            );
+            return self.confirm_panel(&message, option);
+        }
+
+        if command.is_read() {
+            self.stats.read();
+        } else if command.category() == ECommandCategory::Write {
+            self.stats.write();
+        }
+
+        match command.key() {
             HOME => self.clone().home(&self.default_header()),
             STATUS => self.clone().status().await,
+            HELP => self.clone().help(),
+            VERSION => self.clone().version(),
+            REDRAW | EXPORT_VIEW => self.clone().home_headers(),
 
             TEXT_INPUT => self.clone().translate_query(option).await,
+            SAVE_QUERY => self.clone().save_query(option),
+            SHOW_SAVED => self.clone().show_saved(),
+            RUN_SAVED => self.clone().run_saved(option).await,
+            DELETE_SAVED => self.clone().delete_saved(option),
 
             CREATE_DATABASE => self.clone().create_data_base(option).await,
             DROP_DATABASE => self.clone().drop_data_base().await,
+            SNAPSHOT_DATABASE => self.clone().snapshot_database(option).await,
             SHOW_DATABASES => self.clone().show_databases().await,
+            DATABASES_BY_COUNT => self.clone().databases_by_count().await,
             SELECT_DATABASE_PANEL => self.clone().select_database_panel().await,
             SELECT_DATABASE => self.clone().select_database(option),
+            TOGGLE_DATABASE_RECENCY => self.clone().toggle_database_recency(),
+            CLEAR_DATABASE_HISTORY => self.clone().clear_database_history(),
+            SWITCH_DATABASE => self.clone().switch_database(),
 
             SHOW_COLLECTIONS => self.clone().show_collections().await,
+            SCHEMA_DIFF => self.clone().schema_diff(option).await,
+            CHECK_REFERENCES => self.clone().check_references(option).await,
             SELECT_COLLECTION_PANEL => self.clone().select_collection_panel().await,
             SELECT_COLLECTION => self.clone().select_collection(option),
 
             SHOW_ELEMENTS => self.clone().show_elements().await,
+            FIND_PAGE => self.clone().find_page(option).await,
+            TOGGLE_FULL_LISTING => self.clone().toggle_full_listing(),
+            LATEST => self.clone().latest(option).await,
+            FULLTEXT_SEARCH => self.clone().fulltext_search(option).await,
+            GROUP_COUNT => self.clone().group_count(option).await,
+            CHECKPOINT => self.clone().checkpoint().await,
+            DIFF_CHECKPOINT => self.clone().diff_checkpoint().await,
+            BENCH_QUERY => self.clone().bench_query(option).await,
             SELECT_ELEMENTS_PANEL => self.clone().select_element_panel().await,
+            SET_LABEL_FIELDS => self.clone().set_label_fields(option),
             SELECT_ELEMENT => self.clone().select_element(option),
 
+            MARK_ELEMENTS_PANEL => self.clone().mark_elements_panel(0).await,
+            MARK_ELEMENT => self.clone().mark_element(option).await,
+            MARKED_ACTIONS => self.clone().marked_actions(),
+            MARKED_COPY => self.clone().marked_copy().await,
+            MARKED_EXPORT => self.clone().marked_export(option).await,
+            CLEAR_MARKS => self.clone().clear_marks(),
+
             SHOW_SELECTED => self.clone().show_selected().await,
-            _ => todo!(),
+            SELECTED_ITEM => self.clone().selected_item(option),
+            EXPORT_FILTERED => self.clone().export_filtered(option).await,
+            TOGGLE_COMPACT => self.clone().toggle_compact(),
+            TREE_VIEW => self.clone().tree_view().await,
+            TREE_TOGGLE => self.clone().tree_toggle(option),
+
+            RESET_STATS => self.clone().reset_stats(),
+            CLEAR_CACHE => self.clone().clear_cache(),
+            COPY_CONNECTION => self.clone().copy_connection(),
+            CONFIG_PATH => self.clone().config_path(),
+            START_BATCH => self.clone().start_batch(),
+            RUN_BATCH => self.clone().run_batch(option).await,
+            CLEAR_BATCH => self.clone().clear_batch(),
+            TOGGLE_MENU_MODE => self.clone().toggle_menu_mode(),
+            _ => {
+                self.stats.error();
+                let message = format!("Command '{}' is not available.", command.label());
+                self.failed(&message)
+            }
+        }
+    }
+
+    async fn preview(&self, option: TerminalOption<Self>) -> Option<String>
+    where
+        Self: Sized,
+    {
+        match option.option().as_str() {
+            SELECT_COLLECTION => self.preview_collection(option).await,
+            SELECTED_ITEM => option
+                .args()
+                .first()
+                .map(|element| format!("{}\n\n{}", self.id_line(element), element)),
+            _ => None,
         }
     }
 }
@@ -90,52 +361,193 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             data_base: None,
             collection: None,
             element: None,
+            data_base_history: HashMap::new(),
+            sw_recency_sort: false,
+            stats: SessionStats::new(),
+            sw_protected: false,
+            preview_cache: Arc::new(Mutex::new(HashMap::new())),
+            sw_compact: false,
+            connection: None,
+            tree: None,
+            sw_full_listing: false,
+            marked: BTreeSet::new(),
+            label_fields: TerminalConfiguration::list(terminal_configuration::LABEL_FIELDS),
+            page: None,
+            sw_advanced: TerminalConfiguration::is_advanced_menu(),
+            saved_queries: SavedQueries::load(),
+            checkpoint: None,
+            batch: None,
+            failure: None,
         }
     }
 
-    pub async fn launch(&mut self) -> &Self {
+    pub async fn launch(&mut self) -> io::Result<&Self> {
         let header = self.default_header();
         let cursor = self.home(&header);
-        let _ = TerminalManager::new(cursor).launch().await;
-        return self;
+        TerminalManager::new(cursor).launch().await?;
+        return Ok(self);
+    }
+
+    pub async fn exec(&mut self, query: String, format: EOutputFormat) -> Result<String, String> {
+        let mut fragments = query
+            .split(">")
+            .map(|f| String::from(f))
+            .collect::<Vec<String>>();
+        let first = String::from(fragments.remove(0).trim());
+        if !first.is_empty() && first != "*" {
+            return Err(String::from("Cannot understand query, expected a path starting with '*' or '>'."));
+        }
+
+        self.interpret_path(first, fragments).await?;
+
+        let items = self.exec_items().await?;
+        Ok(format.render(&items))
+    }
+
+    async fn exec_items(&self) -> Result<Vec<String>, String> {
+        if self.element.is_some() {
+            let filter = FilterElement::from_id_chain_collection(self.element.clone().unwrap());
+            let query = DataBaseQuery::from_filter(
+                self.data_base.clone().unwrap(),
+                self.collection.clone().unwrap(),
+                filter,
+            );
+            return self.service.find_query(query).await.map_err(|err| err.message());
+        }
+
+        if self.collection.is_some() {
+            let query = DataBaseQuery::from(
+                self.data_base.clone().unwrap(),
+                self.collection.clone().unwrap(),
+            );
+            return self.service.find_all_lite(query).await.map_err(|err| err.message());
+        }
+
+        if self.data_base.is_some() {
+            let query = DataBaseQuery::from_data_base(self.data_base.clone().unwrap());
+            return self.service.list_collections(query).await.map_err(|err| err.message());
+        }
+
+        self.service.list_data_bases().await.map_err(|err| err.message())
     }
 
     async fn status(self) -> TerminalCursor<Self> {
         let headers = &self.default_header();
-        let mut message = format!(
+        let message = format!(
             "{}{} Status OK.{}",
             terminal_manager::ANSI_COLOR_GREEN,
             terminal_manager::ANSI_BOLD,
             terminal_manager::ANSI_RESET
         );
         if self.service.status().await.is_err() {
-            message = format!(
+            self.stats.error();
+            let message = format!(
                 "{}{} Status KO.{}",
                 terminal_manager::ANSI_COLOR_RED,
                 terminal_manager::ANSI_BOLD,
                 terminal_manager::ANSI_RESET
             );
+            return self.failed_home(&format!("{}\n\n{}", headers, message), &message);
         }
 
         self.home(&format!("{}\n\n{}", headers, message))
     }
 
+    fn version(&self) -> TerminalCursor<Self> {
+        self.home(&self.info_headers(&BuildInfo::summary()))
+    }
+
+    fn help(&self) -> TerminalCursor<Self> {
+        let keys = Vec::from(vec![
+            ("Up / Down", "Move the cursor between options."),
+            ("Enter", "Execute the focused option."),
+            ("t", "Type a query path, e.g. '* > data base > collection > id'."),
+            ("/", "Fuzzy filter the options, an empty filter restores them."),
+            ("Tab", "Cycle among the recently used data bases."),
+            ("PgUp / PgDn", "Scroll long screens."),
+            ("Ctrl-L", "Clear the screen and render it again."),
+            ("Ctrl-E", "Export the current screen as plain text."),
+            ("Esc", "Exit the terminal."),
+        ]);
+
+        let mut lines = Vec::<String>::new();
+
+        lines.push(self.help_group("Keys"));
+        for (key, description) in keys {
+            lines.push(self.help_line(key, description));
+        }
+        lines.push(String::new());
+
+        for category in ECommandCategory::values() {
+            let commands = CommandRegistry::category(category);
+            if commands.is_empty() {
+                continue;
+            }
+
+            lines.push(self.help_group(category.title()));
+            for command in commands {
+                let mut description = String::from(command.description());
+                if command.selection() != ESelectionLevel::None {
+                    description = format!("{} Requires a selected {}.", description, self.selection_title(command.selection()));
+                }
+                if let Some(reason) = command.unsupported_reason() {
+                    description = format!("{} Unsupported by the core: {}", description, reason);
+                }
+                lines.push(self.help_line(command.label(), &description));
+            }
+            lines.push(String::new());
+        }
+
+        lines.push(BuildInfo::summary());
+
+        let header = self.info_headers("Help:");
+        self.home(&format!("{}\n\n{}", header, lines.join("\n").trim_end()))
+    }
+
+    fn help_group(&self, group: &str) -> String {
+        format!(
+            "{}{}{}:{}",
+            terminal_manager::ANSI_COLOR_CYAN,
+            terminal_manager::ANSI_BOLD,
+            group,
+            terminal_manager::ANSI_RESET
+        )
+    }
+
+    fn help_line(&self, command: &str, description: &str) -> String {
+        format!(
+            " - {}{}{}: {}",
+            terminal_manager::ANSI_BOLD,
+            command,
+            terminal_manager::ANSI_RESET,
+            description
+        )
+    }
+
+    fn selection_title(&self, level: ESelectionLevel) -> &'static str {
+        match level {
+            ESelectionLevel::None => "nothing",
+            ESelectionLevel::DataBase => "data base",
+            ESelectionLevel::Collection => "collection",
+            ESelectionLevel::Element => "element",
+        }
+    }
+
     async fn create_data_base(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
         let args = option.args();
-        let mut header = self.info_headers("Cannot create data base.");
-        if args.len() > 0 {
-            let data_base = args.get(0).unwrap().trim().to_string();
-            let query = GenerateDatabaseQuery::new(data_base);
-            let result = self.service.create_data_base(query).await;
-            if result.is_err() {
-                let header = self.info_headers(&result.unwrap_err().message());
-                return self.home(&header);
-            }
+        if args.len() == 0 {
+            return self.failed("Cannot create data base.");
+        }
 
-            header = self.info_headers(&format!("Data base '{}' created", result.unwrap()));
+        let data_base = args.get(0).unwrap().trim().to_string();
+        let query = GenerateDatabaseQuery::new(data_base);
+        let result = self.service.create_data_base(query).await;
+        if result.is_err() {
+            self.stats.error();
+            return self.failed(&result.unwrap_err().message());
         }
 
-        self.home(&header)
+        self.home(&self.info_headers(&format!("Data base '{}' created", result.unwrap())))
     }
 
     async fn drop_data_base(&mut self) -> TerminalCursor<Self> {
@@ -145,8 +557,8 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             let query = GenerateDatabaseQuery::new(data_base);
             let result = self.service.drop_data_base(query).await;
             if result.is_err() {
-                let header = self.info_headers(&result.unwrap_err().message());
-                return self.home(&header);
+                self.stats.error();
+                return self.failed(&result.unwrap_err().message());
             }
 
             self.reset_database();
@@ -160,9 +572,10 @@ impl<T: IDBRepository> ManagerDatabase<T> {
     async fn show_databases(&self) -> TerminalCursor<Self> {
         let result = self.service.list_data_bases().await;
 
-        let mut header = self.info_headers("The repository contains the following data bases:");
+        let header = self.info_headers("The repository contains the following data bases:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.failed(&err.to_string());
         }
 
         let mut vector = Vec::<String>::new();
@@ -170,29 +583,196 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             vector = result.ok().unwrap();
         }
 
-        let mut elements = Vec::<String>::new();
-        for element in vector {
-            elements.push(format!(
-                " - {}{}{}",
-                terminal_manager::ANSI_BOLD,
-                element,
+        self.home(&self.render_items(&header, vector, " - ", "\n"))
+    }
+
+    async fn databases_by_count(&self) -> TerminalCursor<Self> {
+        let result = self.service.list_data_bases().await;
+        if let Err(err) = &result {
+            self.stats.error();
+            return self.failed(&err.to_string());
+        }
+
+        let mut counts = stream::iter(result.ok().unwrap())
+            .map(|data_base| async move {
+                let count = self.data_base_documents(data_base.clone()).await;
+                (data_base, count)
+            })
+            .buffer_unordered(STATS_CONCURRENCY)
+            .collect::<Vec<(String, Result<(usize, usize), String>)>>()
+            .await;
+
+        counts.sort_by(|a, b| {
+            let a_count = a.1.as_ref().map(|c| c.0).ok();
+            let b_count = b.1.as_ref().map(|c| c.0).ok();
+            b_count.cmp(&a_count).then(a.0.cmp(&b.0))
+        });
+
+        let mut lines = Vec::<String>::new();
+        for (data_base, count) in counts {
+            let line = match count {
+                Ok((documents, 0)) => format!("{}: {} documents", data_base, self.group_digits(documents)),
+                Ok((documents, failed)) => format!(
+                    "{}: {} documents{} ({} collections unavailable){}",
+                    data_base,
+                    self.group_digits(documents),
+                    terminal_manager::ANSI_COLOR_YELLOW,
+                    self.group_digits(failed),
+                    terminal_manager::ANSI_RESET
+                ),
+                Err(error) => {
+                    self.stats.error();
+                    format!("{}{}: unavailable, {}{}", terminal_manager::ANSI_COLOR_RED, data_base, error, terminal_manager::ANSI_RESET)
+                }
+            };
+            lines.push(line);
+        }
+
+        let header = self.info_headers("Data bases by document count, every collection of every data base is scanned:");
+        self.home(&self.render_items(&header, lines, " - ", "\n"))
+    }
+
+    async fn data_base_documents(&self, data_base: String) -> Result<(usize, usize), String> {
+        let query = DataBaseQuery::from_data_base(data_base.clone());
+        let collections = self.service.list_collections(query).await.map_err(|err| err.to_string())?;
+
+        let mut documents = 0;
+        let mut failed = 0;
+        for collection in collections {
+            let query = DataBaseQuery::from(data_base.clone(), collection);
+            match self.service.find_all_lite(query).await {
+                Ok(elements) => documents = documents + elements.len(),
+                Err(_) => failed = failed + 1,
+            }
+        }
+
+        Ok((documents, failed))
+    }
+
+    async fn snapshot_database(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_database() {
+            return self.failed(&error.message());
+        }
+
+        let directory = option.args().first().map(|d| String::from(d.trim())).unwrap_or_default();
+        if directory.is_empty() {
+            return self.failed("Snapshot cancelled, no directory given.");
+        }
+
+        let data_base = self.data_base.clone().unwrap();
+        let query = DataBaseQuery::from_data_base(data_base.clone());
+        let collections = match self.service.list_collections(query).await {
+            Ok(collections) => collections,
+            Err(err) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        if let Err(error) = fs::create_dir_all(&directory) {
+            self.stats.error();
+            return self.failed(&format!("Cannot create directory '{}': {}", directory, error));
+        }
+
+        let directory = Path::new(&directory);
+        let mut results = stream::iter(collections)
+            .map(|collection| async move {
+                let result = self.snapshot_collection(directory, collection.clone()).await;
+                (collection, result)
+            })
+            .buffer_unordered(STATS_CONCURRENCY)
+            .collect::<Vec<(String, Result<(String, usize), String>)>>()
+            .await;
+
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut manifest = Vec::<Value>::new();
+        let mut lines = Vec::<String>::new();
+        let mut failures = 0;
+        for (collection, result) in results {
+            match result {
+                Ok((file, documents)) => {
+                    let mut entry = Map::new();
+                    entry.insert(String::from("name"), Value::String(collection.clone()));
+                    entry.insert(String::from("file"), Value::String(file));
+                    entry.insert(String::from("documents"), Value::from(documents));
+                    manifest.push(Value::Object(entry));
+                    lines.push(format!("{}: {} documents", collection, self.group_digits(documents)));
+                }
+                Err(error) => {
+                    self.stats.error();
+                    failures += 1;
+                    lines.push(format!(
+                        "{}{}: {}{}",
+                        terminal_manager::ANSI_COLOR_RED,
+                        collection,
+                        error,
+                        terminal_manager::ANSI_RESET
+                    ));
+                }
+            }
+        }
+
+        let mut root = Map::new();
+        root.insert(String::from("data_base"), Value::String(data_base.clone()));
+        root.insert(String::from("collections"), Value::Array(manifest));
+
+        let manifest = directory.join("manifest.json");
+        let json = serde_json::to_string_pretty(&Value::Object(root)).unwrap_or_default();
+        if let Err(error) = fs::write(&manifest, json) {
+            self.stats.error();
+            failures += 1;
+            lines.push(format!(
+                "{}Cannot write manifest: {}{}",
+                terminal_manager::ANSI_COLOR_RED,
+                error,
                 terminal_manager::ANSI_RESET
             ));
         }
 
-        if !elements.is_empty() {
-            header = format!("{}\n", header);
+        let header = self.info_headers(&format!(
+            "Snapshot of '{}' written to '{}':",
+            data_base,
+            directory.display()
+        ));
+        let body = self.render_items(&header, lines, " - ", "\n");
+        if failures > 0 {
+            return self.failed_home(&body, &format!("{} snapshot writes failed", self.group_digits(failures)));
         }
 
-        self.home(&format!("{}\n{}", header, elements.join("\n")))
+        self.home(&body)
+    }
+
+    async fn snapshot_collection(&self, directory: &Path, collection: String) -> Result<(String, usize), String> {
+        let query = DataBaseQuery::from(self.data_base.clone().unwrap(), collection.clone());
+        let documents = self.service.find_query(query).await.map_err(|err| err.to_string())?;
+
+        let file = format!("{}.ndjson", collection.replace(['/', '\\'], "_"));
+        fs::write(directory.join(&file), self.ndjson(&documents)).map_err(|error| error.to_string())?;
+
+        Ok((file, documents.len()))
+    }
+
+    fn ndjson(&self, documents: &[String]) -> String {
+        let mut lines = String::new();
+        for document in documents {
+            let line = match serde_json::from_str::<Value>(document) {
+                Ok(value) => value.to_string(),
+                Err(_) => document.replace("\n", " "),
+            };
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+        lines
     }
 
     async fn select_database_panel(&self) -> TerminalCursor<Self> {
         let result = self.service.list_data_bases().await;
 
-        let mut header = self.info_headers("Select one of the following data bases:");
+        let header = self.info_headers("Select one of the following data bases:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.failed(&err.to_string());
         }
 
         let mut vector = Vec::<String>::new();
@@ -200,23 +780,30 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             vector = result.ok().unwrap();
         }
 
+        vector.sort();
+        if self.sw_recency_sort {
+            vector.sort_by(|a, b| self.data_base_history.get(b).cmp(&self.data_base_history.get(a)));
+        }
+
         let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
 
         for element in vector {
             let args = Vec::from(vec![element.clone()]);
+            let mut title = element.clone();
+            if self.sw_recency_sort {
+                if let Some(time) = self.data_base_history.get(&element) {
+                    title = format!("{} (last accessed {} ago)", element, self.elapsed_label(time));
+                }
+            }
             cursor.push(TerminalOption::from_args(
-                element,
+                title,
                 SELECT_DATABASE,
                 args,
                 self.clone(),
             ));
         }
 
-        cursor.push(TerminalOption::from(
-            String::from("[None]"),
-            SELECT_DATABASE,
-            self.clone(),
-        ));
+        self.push_none(&mut cursor, SELECT_DATABASE);
 
         cursor
     }
@@ -225,7 +812,12 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         let args = option.args();
         if args.len() > 0 {
             let data_base = args.get(0).unwrap().to_string();
-            self.data_base = Some(data_base);
+            if self.data_base.as_ref() != Some(&data_base) {
+                self.marked.clear();
+                self.page = None;
+            }
+            self.data_base = Some(data_base.clone());
+            self.record_database_access(data_base);
         } else {
             self.reset_database();
         }
@@ -233,19 +825,62 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         self.home_headers()
     }
 
+    // Moves to the least recent of the last used data bases.
+    fn switch_database(&mut self) -> TerminalCursor<Self> {
+        let mut recent = self.data_base_history.iter().collect::<Vec<(&String, &SystemTime)>>();
+        recent.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+        let target = recent
+            .into_iter()
+            .take(QUICK_SWITCH_DEPTH)
+            .map(|(data_base, _)| data_base.clone())
+            .filter(|data_base| self.data_base.as_ref() != Some(data_base))
+            .last();
+
+        let data_base = match target {
+            Some(data_base) => data_base,
+            None => return self.failed("Quick switch needs at least two used data bases."),
+        };
+
+        self.reset_collection();
+        self.data_base = Some(data_base.clone());
+        self.record_database_access(data_base.clone());
+
+        self.home(&self.info_headers(&format!("Switched to data base '{}'.", data_base)))
+    }
+
+    fn toggle_database_recency(&mut self) -> TerminalCursor<Self> {
+        self.sw_recency_sort = !self.sw_recency_sort;
+
+        let mut header = self.info_headers("Data bases will be sorted alphabetically.");
+        if self.sw_recency_sort {
+            header = self.info_headers("Data bases will be sorted by last access.");
+        }
+
+        self.home(&header)
+    }
+
+    fn clear_database_history(&mut self) -> TerminalCursor<Self> {
+        let count = self.data_base_history.len();
+        self.data_base_history.clear();
+
+        let header = self.info_headers(&format!("Access history cleared, {} data bases forgotten.", self.group_digits(count)));
+        self.home(&header)
+    }
+
     async fn show_collections(&self) -> TerminalCursor<Self> {
         if let Err(error) = self.verify_database() {
-            let header = self.info_headers(&error.message());
-            return self.home(&header);
+            return self.failed(&error.message());
         }
 
         let query = DataBaseQuery::from_data_base(self.data_base.clone().unwrap());
 
         let result = self.service.list_collections(query).await;
 
-        let mut header = self.info_headers("The repository contains the following collections:");
+        let header = self.info_headers("The repository contains the following collections:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.failed(&err.to_string());
         }
 
         let mut vector = Vec::<String>::new();
@@ -253,36 +888,181 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             vector = result.ok().unwrap();
         }
 
-        let mut elements = Vec::<String>::new();
-        for element in vector {
-            elements.push(format!(
-                " - {}{}{}",
-                terminal_manager::ANSI_BOLD,
-                element,
-                terminal_manager::ANSI_RESET
-            ));
+        self.home(&self.render_items(&header, vector, " - ", "\n"))
+    }
+
+    async fn schema_diff(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
         }
 
-        if !elements.is_empty() {
-            header = format!("{}\n", header);
+        let input = option.args().first().cloned().unwrap_or_default();
+        let (data_base, collection) = match self.collection_target(self.path_fragments(&input)) {
+            Some(target) => target,
+            None => {
+                return self.failed("Invalid collection, expected '[data base >] collection'.");
+            }
+        };
+
+        let source = format!("{}.{}", self.data_base.clone().unwrap(), self.collection.clone().unwrap());
+        let target = format!("{}.{}", data_base, collection);
+
+        let left = DataBaseQuery::from(self.data_base.clone().unwrap(), self.collection.clone().unwrap());
+        let right = DataBaseQuery::from(data_base, collection);
+
+        let (left, right) = match (self.service.find_query(left).await, self.service.find_query(right).await) {
+            (Ok(left), Ok(right)) => (Schema::infer(&left), Schema::infer(&right)),
+            (Err(err), _) | (_, Err(err)) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let mut lines = Vec::<String>::new();
+        for (field, types) in left.fields() {
+            match right.types(field) {
+                None => lines.push(format!(
+                    "{} - {} ({}) only in '{}'{}",
+                    terminal_manager::ANSI_COLOR_RED,
+                    field,
+                    self.schema_types(types),
+                    source,
+                    terminal_manager::ANSI_RESET
+                )),
+                Some(other) if other != types => lines.push(format!(
+                    "{} ~ {}: {} in '{}', {} in '{}'{}",
+                    terminal_manager::ANSI_COLOR_YELLOW,
+                    field,
+                    self.schema_types(types),
+                    source,
+                    self.schema_types(other),
+                    target,
+                    terminal_manager::ANSI_RESET
+                )),
+                Some(_) => {}
+            }
         }
 
-        self.home(&format!("{}\n{}", header, elements.join("\n")))
+        for (field, types) in right.fields() {
+            if left.types(field).is_none() {
+                lines.push(format!(
+                    "{} + {} ({}) only in '{}'{}",
+                    terminal_manager::ANSI_COLOR_GREEN,
+                    field,
+                    self.schema_types(types),
+                    target,
+                    terminal_manager::ANSI_RESET
+                ));
+            }
+        }
+
+        if lines.is_empty() {
+            let header = self.info_headers(&format!("Schemas of '{}' and '{}' match.", source, target));
+            return self.home(&header);
+        }
+
+        let header = self.info_headers(&format!("Schema differences between '{}' and '{}':", source, target));
+        self.home(&self.render_items(&header, lines, "", "\n"))
+    }
+
+    async fn check_references(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let input = option.args().first().cloned().unwrap_or_default();
+        let mut fragments = self.path_fragments(&input);
+        let target = match fragments.len() {
+            0 => None,
+            _ => {
+                let field = fragments.remove(0);
+                self.collection_target(fragments).map(|(data_base, collection)| (field, data_base, collection))
+            }
+        };
+
+        let (field, data_base, collection) = match target {
+            Some(target) => target,
+            None => {
+                return self.failed("Invalid reference, expected 'field > [data base >] collection'.");
+            }
+        };
+
+        let source = DataBaseQuery::from(self.data_base.clone().unwrap(), self.collection.clone().unwrap());
+        let target = DataBaseQuery::from(data_base.clone(), collection.clone());
+
+        let (documents, targets) = match (self.service.find_query(source).await, self.service.find_query(target).await) {
+            (Ok(documents), Ok(targets)) => (documents, targets),
+            (Err(err), _) | (_, Err(err)) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let ids = targets
+            .iter()
+            .filter_map(|document| serde_json::from_str::<Value>(document).ok())
+            .filter_map(|document| document.get("_id").map(|id| id.to_string()))
+            .collect::<HashSet<String>>();
+
+        let pointer = format!("/{}", field.replace(".", "/"));
+        let mut checked = 0;
+        let mut orphans = Vec::<String>::new();
+        for document in documents.iter().filter_map(|d| serde_json::from_str::<Value>(d).ok()) {
+            let references = match document.pointer(&pointer) {
+                None | Some(Value::Null) => continue,
+                Some(Value::Array(values)) => values.clone(),
+                Some(value) => Vec::from(vec![value.clone()]),
+            };
+
+            let owner = document.get("_id").map(|id| id.to_string()).unwrap_or_default();
+            for reference in references {
+                checked = checked + 1;
+                if !ids.contains(&reference.to_string()) {
+                    orphans.push(format!("{} -> {}", owner, reference));
+                }
+            }
+        }
+
+        let target = format!("{}.{}", data_base, collection);
+        if orphans.is_empty() {
+            let header = self.info_headers(&format!(
+                "All {} references in '{}' point to documents in '{}'.",
+                self.group_digits(checked),
+                field,
+                target
+            ));
+            return self.home(&header);
+        }
+
+        let header = self.info_headers(&format!(
+            "{}{} of {} references in '{}' have no document in '{}':{}",
+            terminal_manager::ANSI_COLOR_RED,
+            self.group_digits(orphans.len()),
+            self.group_digits(checked),
+            field,
+            target,
+            terminal_manager::ANSI_RESET
+        ));
+        self.home(&self.render_items(&header, orphans, " - ", "\n"))
+    }
+
+    fn schema_types(&self, types: &BTreeSet<String>) -> String {
+        types.iter().cloned().collect::<Vec<String>>().join(" | ")
     }
 
     async fn select_collection_panel(&self) -> TerminalCursor<Self> {
         if let Err(error) = self.verify_database() {
-            let header = self.info_headers(&error.message());
-            return self.home(&header);
+            return self.failed(&error.message());
         }
 
         let query = DataBaseQuery::from_data_base(self.data_base.clone().unwrap());
 
         let result = self.service.list_collections(query).await;
 
-        let mut header = self.info_headers("Select one of the following collections:");
+        let header = self.info_headers("Select one of the following collections:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.failed(&err.to_string());
         }
 
         let mut vector = Vec::<String>::new();
@@ -302,19 +1082,37 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             ));
         }
 
-        cursor.push(TerminalOption::from(
-            String::from("[None]"),
-            SELECT_COLLECTION,
-            self.clone(),
-        ));
+        self.push_none(&mut cursor, SELECT_COLLECTION);
 
         cursor
     }
 
+    async fn preview_collection(&self, option: TerminalOption<Self>) -> Option<String> {
+        let collection = option.args().first()?.clone();
+        let data_base = self.data_base.clone()?;
+
+        let key = format!("{}.{}", data_base, collection);
+        if let Some(preview) = self.preview_cache.lock().unwrap().get(&key) {
+            return Some(preview.clone());
+        }
+
+        let query = DataBaseQuery::from(data_base, collection);
+        let preview = self.service.find(query).await.ok()
+            .flatten()
+            .unwrap_or(String::from("No preview available."));
+
+        self.preview_cache.lock().unwrap().insert(key, preview.clone());
+
+        Some(preview)
+    }
+
     fn select_collection(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
         let args = option.args();
         if args.len() > 0 {
             let collection = args.get(0).unwrap().to_string();
+            if self.collection.as_ref() != Some(&collection) {
+                self.reset_collection();
+            }
             self.collection = Some(collection);
         } else {
             self.reset_collection();
@@ -325,8 +1123,7 @@ impl<T: IDBRepository> ManagerDatabase<T> {
 
     async fn show_elements(&self) -> TerminalCursor<Self> {
         if let Err(error) = self.verify_collection() {
-            let header = self.info_headers(&error.message());
-            return self.home(&header);
+            return self.failed(&error.message());
         }
 
         let query = DataBaseQuery::from(
@@ -334,11 +1131,16 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             self.collection.clone().unwrap(),
         );
 
-        let result = self.service.find_all_lite(query).await;
+        let result = if self.sw_full_listing {
+            self.service.find_query(query).await
+        } else {
+            self.service.find_all_lite(query).await
+        };
 
-        let mut header = self.info_headers("The repository contains the following items:");
+        let header = self.info_headers("The repository contains the following items:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.failed(&err.to_string());
         }
 
         let mut vector = Vec::<String>::new();
@@ -346,67 +1148,670 @@ impl<T: IDBRepository> ManagerDatabase<T> {
             vector = result.ok().unwrap();
         }
 
-        let mut elements = Vec::<String>::new();
-        for element in vector {
-            elements.push(format!(
-                " - {}{}{}",
-                terminal_manager::ANSI_BOLD,
-                element,
-                terminal_manager::ANSI_RESET
-            ));
+        if self.sw_full_listing {
+            vector = vector
+                .into_iter()
+                .map(|document| match self.modified_at(&document) {
+                    Some(modified) => format!("{} (modified {})", document, modified),
+                    None => document,
+                })
+                .collect::<Vec<String>>();
         }
 
-        if !elements.is_empty() {
-            header = format!("{}\n", header);
+        self.home(&self.render_items(&header, vector, " - ", "\n"))
+    }
+
+    fn toggle_full_listing(&mut self) -> TerminalCursor<Self> {
+        self.sw_full_listing = !self.sw_full_listing;
+
+        let mut header = self.info_headers("Elements will be listed in lite form.");
+        if self.sw_full_listing {
+            header = self.info_headers(&format!(
+                "{}Elements will be listed as full documents, this is heavier to read.{}",
+                terminal_manager::ANSI_COLOR_YELLOW,
+                terminal_manager::ANSI_RESET
+            ));
         }
 
-        self.home(&format!("{}\n{}", header, elements.join("\n")))
+        self.home(&header)
     }
 
-    async fn select_element_panel(&self) -> TerminalCursor<Self> {
+    async fn find_page(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
         if let Err(error) = self.verify_collection() {
-            let header = self.info_headers(&error.message());
-            return self.home(&header);
+            return self.failed(&error.message());
         }
 
+        let input = option.args().first().cloned().unwrap_or_default();
+        let (limit, skip) = match self.page_bounds(&input) {
+            Ok(bounds) => bounds,
+            Err(error) => return self.failed(&error),
+        };
+
         let query = DataBaseQuery::from(
             self.data_base.clone().unwrap(),
             self.collection.clone().unwrap(),
         );
 
         let result = self.service.find_all_lite(query).await;
-
-        let mut header = self.info_headers("Select one of the following elements:");
         if let Err(err) = &result {
-            header = err.to_string();
+            self.stats.error();
+            return self.page_lost(&err.to_string(), option);
         }
 
-        let mut vector = Vec::<String>::new();
+        let vector = result.ok().unwrap();
+        let total = vector.len();
+        let elements = vector.into_iter().skip(skip).take(limit).collect::<Vec<String>>();
+
+        let mut header = self.info_headers(&format!(
+            "No items found skipping {} of {} items, paged client-side over the full listing.",
+            self.group_digits(skip),
+            self.group_digits(total)
+        ));
+        if !elements.is_empty() {
+            header = self.info_headers(&format!(
+                "Items {}-{} of {}, paged client-side over the full listing:",
+                self.group_digits(skip + 1),
+                self.group_digits(skip + elements.len()),
+                self.group_digits(total)
+            ));
+        }
+
+        let items = self.render_items("", elements, " - ", "\n");
+        self.page = Some(String::from(items.trim_start_matches('\n')));
+        self.home(&format!("{}{}", header, items))
+    }
+
+    fn page_lost(&self, error: &str, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let mut header = self.info_headers(&format!(
+            "{}Connection lost: {}{}",
+            terminal_manager::ANSI_COLOR_RED,
+            error,
+            terminal_manager::ANSI_RESET
+        ));
+        if let Some(page) = &self.page {
+            header = format!("{}\nLast page preserved:\n\n{}", header, page);
+        }
+
+        let mut manager = self.clone();
+        manager.failure = Some(format!("Connection lost: {}", error));
+
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(manager, &header);
+        cursor.push(TerminalOption::from_args(
+            String::from("Retry"),
+            FIND_PAGE,
+            option.args(),
+            self.clone(),
+        ));
+        cursor.push(TerminalOption::from(String::from("[Home]"), HOME, self.clone()));
+
+        cursor
+    }
+
+    async fn latest(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let message = "Invalid input, expected '[count] [field]' with a positive count.";
+        let input = option.args().first().cloned().unwrap_or_default();
+        let mut values = input.split_whitespace();
+
+        let mut count = TerminalConfiguration::variable(terminal_configuration::LATEST_COUNT)
+            .and_then(|count| count.parse::<usize>().ok())
+            .unwrap_or(LATEST_COUNT);
+        let mut field = String::from("_id");
+
+        if let Some(value) = values.next() {
+            match value.parse::<usize>() {
+                Ok(value) if value > 0 => count = value,
+                Ok(_) => return self.failed(message),
+                Err(_) => field = String::from(value),
+            }
+        }
+        if let Some(value) = values.next() {
+            field = String::from(value);
+        }
+        if values.next().is_some() {
+            return self.failed(message);
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let documents = match self.service.find_query(query).await {
+            Ok(documents) => documents,
+            Err(err) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let pointer = format!("/{}", field.replace(".", "/"));
+        let mut documents = documents
+            .into_iter()
+            .map(|document| {
+                let key = serde_json::from_str::<Value>(&document)
+                    .ok()
+                    .and_then(|value| value.pointer(&pointer).cloned());
+                (key, document)
+            })
+            .collect::<Vec<(Option<Value>, String)>>();
+
+        documents.sort_by(|a, b| self.compare_values(&b.0, &a.0));
+
+        let elements = documents
+            .into_iter()
+            .take(count)
+            .map(|(_, document)| document)
+            .collect::<Vec<String>>();
+
+        let header = self.info_headers(&format!(
+            "Latest {} items by '{}':",
+            self.group_digits(elements.len()),
+            field
+        ));
+        self.home(&self.render_items(&header, elements, " ", "\n\n"))
+    }
+
+    async fn fulltext_search(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let term = option.args().first().map(|t| String::from(t.trim())).unwrap_or_default();
+        if term.is_empty() {
+            return self.failed("Search cancelled, no term given.");
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let documents = match self.service.find_query(query).await {
+            Ok(documents) => documents,
+            Err(err) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let needle = term.to_lowercase();
+        let width = terminal_manager::terminal_width().saturating_sub(3);
+
+        let mut matches = Vec::<String>::new();
+        for document in documents.iter().filter_map(|d| serde_json::from_str::<Value>(d).ok()) {
+            let mut fields = Vec::<(String, String)>::new();
+            self.search_strings("", &document, &needle, &mut fields);
+            if fields.is_empty() {
+                continue;
+            }
+
+            let id = self.id_line(&document.to_string());
+            let fields = fields
+                .iter()
+                .map(|(field, value)| {
+                    let value = terminal_manager::single_line(value, width);
+                    format!("   {}: {}", field, self.highlight_term(&value, &needle))
+                })
+                .collect::<Vec<String>>();
+            matches.push(format!("{}\n{}", id, fields.join("\n")));
+        }
+
+        let warning = format!(
+            "{}Client-side scan: the core exposes no text search, every document was read.{}",
+            terminal_manager::ANSI_COLOR_YELLOW,
+            terminal_manager::ANSI_RESET
+        );
+        let header = self.info_headers(&format!(
+            "{}\n{} of {} items match '{}':",
+            warning,
+            self.group_digits(matches.len()),
+            self.group_digits(documents.len()),
+            term
+        ));
+        self.home(&self.render_items(&header, matches, " ", "\n\n"))
+    }
+
+    async fn group_count(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let field = option.args().first().map(|f| String::from(f.trim())).unwrap_or_default();
+        if field.is_empty() {
+            return self.failed("Group cancelled, no field given.");
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let documents = match self.service.find_query(query).await {
+            Ok(documents) => documents,
+            Err(err) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let pointer = format!("/{}", field.replace(".", "/"));
+        let mut groups = HashMap::<String, usize>::new();
+        for document in documents.iter().filter_map(|d| serde_json::from_str::<Value>(d).ok()) {
+            let group = match document.pointer(&pointer) {
+                None => String::from("(missing)"),
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+            };
+            *groups.entry(group).or_insert(0) += 1;
+        }
+
+        let mut groups = groups.into_iter().collect::<Vec<(String, usize)>>();
+        groups.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let total = groups.len();
+        let highest = groups.first().map(|g| g.1).unwrap_or(0).max(1);
+        let label_width = terminal_manager::terminal_width().saturating_sub(GROUP_BAR_WIDTH + 16).max(8);
+
+        let mut lines = groups
+            .into_iter()
+            .take(GROUP_LIMIT)
+            .map(|(group, count)| {
+                let bar = "#".repeat((count * GROUP_BAR_WIDTH).div_ceil(highest));
+                format!(
+                    "{} {}{}{} {}",
+                    terminal_manager::pad(&terminal_manager::truncate(&group, label_width), label_width),
+                    terminal_manager::ANSI_COLOR_CYAN,
+                    bar,
+                    terminal_manager::ANSI_RESET,
+                    self.group_digits(count)
+                )
+            })
+            .collect::<Vec<String>>();
+
+        if total > GROUP_LIMIT {
+            lines.push(format!("... and {} more groups.", self.group_digits(total - GROUP_LIMIT)));
+        }
+
+        let header = self.info_headers(&format!(
+            "{} items grouped by '{}' in {} groups:",
+            self.group_digits(documents.len()),
+            field,
+            self.group_digits(total)
+        ));
+        self.home(&self.render_items(&header, lines, " ", "\n"))
+    }
+
+    async fn collection_hashes(&self) -> Result<BTreeMap<String, u64>, String> {
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let documents = self.service.find_query(query).await.map_err(|err| {
+            self.stats.error();
+            err.to_string()
+        })?;
+
+        let mut hashes = BTreeMap::new();
+        for document in documents {
+            let value = match serde_json::from_str::<Value>(&document) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            let id = value.get("_id").map(|id| id.to_string()).unwrap_or_default();
+
+            let mut hasher = DefaultHasher::new();
+            value.to_string().hash(&mut hasher);
+            hashes.insert(id, hasher.finish());
+        }
+
+        Ok(hashes)
+    }
+
+    fn checkpoint_key(&self) -> String {
+        format!("{}.{}", self.data_base.clone().unwrap(), self.collection.clone().unwrap())
+    }
+
+    async fn checkpoint(&mut self) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let hashes = match self.collection_hashes().await {
+            Ok(hashes) => hashes,
+            Err(error) => return self.failed(&error),
+        };
+
+        let key = self.checkpoint_key();
+        let header = self.info_headers(&format!(
+            "Checkpoint of '{}' taken with {} items.",
+            key,
+            self.group_digits(hashes.len())
+        ));
+        self.checkpoint = Some((key, hashes));
+
+        self.home(&header)
+    }
+
+    async fn diff_checkpoint(&self) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let key = self.checkpoint_key();
+        let previous = match &self.checkpoint {
+            Some((checkpoint, hashes)) if *checkpoint == key => hashes,
+            Some((checkpoint, _)) => {
+                return self.failed(&format!("The checkpoint was taken on '{}', not '{}'.", checkpoint, key));
+            }
+            None => return self.failed("There is no checkpoint to compare with."),
+        };
+
+        let current = match self.collection_hashes().await {
+            Ok(hashes) => hashes,
+            Err(error) => return self.failed(&error),
+        };
+
+        let mut lines = Vec::<String>::new();
+        let (mut added, mut removed, mut modified) = (0, 0, 0);
+        for (id, hash) in &current {
+            let (mark, color) = match previous.get(id) {
+                None => {
+                    added = added + 1;
+                    ("+", terminal_manager::ANSI_COLOR_GREEN)
+                }
+                Some(previous) if previous != hash => {
+                    modified = modified + 1;
+                    ("~", terminal_manager::ANSI_COLOR_YELLOW)
+                }
+                Some(_) => continue,
+            };
+            lines.push(format!("{}{} {}{}", color, mark, id, terminal_manager::ANSI_RESET));
+        }
+        for id in previous.keys().filter(|id| !current.contains_key(*id)) {
+            removed = removed + 1;
+            lines.push(format!("{}- {}{}", terminal_manager::ANSI_COLOR_RED, id, terminal_manager::ANSI_RESET));
+        }
+
+        if lines.is_empty() {
+            return self.home(&self.info_headers(&format!("'{}' is unchanged since the checkpoint.", key)));
+        }
+
+        let header = self.info_headers(&format!(
+            "Changes in '{}' since the checkpoint, {} added, {} removed, {} modified:",
+            key,
+            self.group_digits(added),
+            self.group_digits(removed),
+            self.group_digits(modified)
+        ));
+        self.home(&self.render_items(&header, lines, "", "\n"))
+    }
+
+    fn search_strings(&self, path: &str, value: &Value, needle: &str, fields: &mut Vec<(String, String)>) {
+        match value {
+            Value::String(text) if text.to_lowercase().contains(needle) => {
+                fields.push((String::from(path), text.clone()));
+            }
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                    self.search_strings(&path, value, needle, fields);
+                }
+            }
+            Value::Array(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    self.search_strings(&format!("{}.{}", path, index), value, needle, fields);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn highlight_term(&self, text: &str, needle: &str) -> String {
+        let lower = text.to_lowercase();
+        if lower.len() != text.len() {
+            return String::from(text);
+        }
+
+        let mut highlighted = String::new();
+        let mut last = 0;
+        for (start, _) in lower.match_indices(needle) {
+            if !text.is_char_boundary(start) || !text.is_char_boundary(start + needle.len()) {
+                return String::from(text);
+            }
+            highlighted.push_str(&text[last..start]);
+            highlighted.push_str(&format!(
+                "{}{}{}",
+                terminal_manager::ANSI_COLOR_CYAN,
+                &text[start..start + needle.len()],
+                terminal_manager::ANSI_RESET
+            ));
+            last = start + needle.len();
+        }
+        highlighted.push_str(&text[last..]);
+        highlighted
+    }
+
+    // Missing values first, then numbers, then anything else as text.
+    fn compare_values(&self, a: &Option<Value>, b: &Option<Value>) -> Ordering {
+        let unwrap = |value: &Value| -> Value {
+            match value {
+                Value::Object(map) if map.len() == 1 => {
+                    let inner = map.values().next().unwrap();
+                    match inner {
+                        Value::Object(inner) if inner.len() == 1 => inner.values().next().unwrap().clone(),
+                        _ => inner.clone(),
+                    }
+                }
+                _ => value.clone(),
+            }
+        };
+
+        match (a.as_ref().map(unwrap), b.as_ref().map(unwrap)) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(Value::Number(a)), Some(Value::Number(b))) => a
+                .as_f64()
+                .partial_cmp(&b.as_f64())
+                .unwrap_or(Ordering::Equal),
+            (Some(Value::Number(_)), Some(_)) => Ordering::Less,
+            (Some(_), Some(Value::Number(_))) => Ordering::Greater,
+            (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(&b),
+            (Some(a), Some(b)) => a.to_string().cmp(&b.to_string()),
+        }
+    }
+
+    async fn bench_query(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let input = option.args().first().cloned().unwrap_or_default();
+        let iterations = match input.trim().parse::<usize>() {
+            Ok(iterations) if iterations > 0 && iterations <= BENCH_MAX_ITERATIONS => iterations,
+            _ => {
+                return self.failed(&format!(
+                    "Invalid iterations, expected a number between 1 and {}.",
+                    BENCH_MAX_ITERATIONS
+                ));
+            }
+        };
+
+        let mut latencies = Vec::<Duration>::new();
+        for _ in 0..iterations {
+            let query = match &self.element {
+                Some(element) => DataBaseQuery::from_filter(
+                    self.data_base.clone().unwrap(),
+                    self.collection.clone().unwrap(),
+                    FilterElement::from_id_chain_collection(element.clone()),
+                ),
+                None => DataBaseQuery::from(
+                    self.data_base.clone().unwrap(),
+                    self.collection.clone().unwrap(),
+                ),
+            };
+
+            let start = Instant::now();
+            let result = self.service.find_query(query).await;
+            latencies.push(start.elapsed());
+
+            if let Err(err) = result {
+                self.stats.error();
+                return self.failed(&format!(
+                    "Benchmark stopped after {} runs: {}",
+                    self.group_digits(latencies.len()),
+                    err
+                ));
+            }
+        }
+
+        latencies.sort();
+
+        let rows = Vec::from(vec![
+            ("min", latencies[0]),
+            ("p50", self.percentile(&latencies, 50)),
+            ("p95", self.percentile(&latencies, 95)),
+            ("max", latencies[latencies.len() - 1]),
+        ]);
+
+        let table = rows
+            .iter()
+            .map(|(label, latency)| {
+                format!(
+                    " {}{:<4}{} {}{:>10.3} ms{}",
+                    terminal_manager::ANSI_BOLD,
+                    label,
+                    terminal_manager::ANSI_RESET,
+                    terminal_manager::ANSI_COLOR_CYAN,
+                    latency.as_secs_f64() * 1000.0,
+                    terminal_manager::ANSI_RESET
+                )
+            })
+            .collect::<Vec<String>>();
+
+        let target = if self.element.is_some() { "the selected elements" } else { "the collection" };
+        let header = self.info_headers(&format!(
+            "Latency of {} sequential finds over {}:",
+            self.group_digits(iterations),
+            target
+        ));
+        self.home(&self.render_items(&header, table, "", "\n"))
+    }
+
+    fn percentile(&self, sorted: &[Duration], percent: usize) -> Duration {
+        let rank = (sorted.len() * percent).div_ceil(100);
+        sorted[rank.saturating_sub(1)]
+    }
+
+    async fn select_element_panel(&self) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let result = self.service.find_all_lite(query).await;
+
+        let header = self.info_headers("Select one of the following elements:");
+        if let Err(err) = &result {
+            self.stats.error();
+            return self.failed(&err.to_string());
+        }
+
+        let mut vector = Vec::<String>::new();
         if result.is_ok() {
             vector = result.ok().unwrap();
         }
 
+        let labels = self.element_labels().await;
+
         let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
 
         for element in vector {
             let args = Vec::from(vec![element.clone()]);
+            let title = labels
+                .get(&element)
+                .cloned()
+                .unwrap_or(element);
             cursor.push(TerminalOption::from_args(
-                element,
+                title,
                 SELECT_ELEMENT,
                 args,
                 self.clone(),
             ));
         }
 
-        cursor.push(TerminalOption::from(
-            String::from("[None]"),
-            SELECT_ELEMENT,
-            self.clone(),
-        ));
+        self.push_none(&mut cursor, SELECT_ELEMENT);
 
         cursor
     }
 
+    async fn element_labels(&self) -> HashMap<String, String> {
+        if self.label_fields.is_empty() {
+            return HashMap::new();
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let documents = match self.service.find_query(query).await {
+            Ok(documents) => documents,
+            Err(_) => return HashMap::new(),
+        };
+
+        documents
+            .iter()
+            .filter_map(|document| {
+                let value = serde_json::from_str::<Value>(document).ok()?;
+                let id = self.document_id(&value)?;
+                let fields = self
+                    .label_fields
+                    .iter()
+                    .filter_map(|field| {
+                        let pointer = format!("/{}", field.replace(".", "/"));
+                        value.pointer(&pointer).map(|v| match v {
+                            Value::String(text) => format!("{}: {}", field, text),
+                            _ => format!("{}: {}", field, v),
+                        })
+                    })
+                    .collect::<Vec<String>>();
+                if fields.is_empty() {
+                    return None;
+                }
+                Some((id, fields.join(", ")))
+            })
+            .collect::<HashMap<String, String>>()
+    }
+
+    fn set_label_fields(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let input = option.args().first().cloned().unwrap_or_default();
+        self.label_fields = input
+            .split(",")
+            .map(|f| String::from(f.trim()))
+            .filter(|f| !f.is_empty())
+            .collect::<Vec<String>>();
+
+        if self.label_fields.is_empty() {
+            return self.home(&self.info_headers("Elements will be labelled with their lite form."));
+        }
+
+        let header = self.info_headers(&format!(
+            "Elements will be labelled with: {}.",
+            self.label_fields.join(", ")
+        ));
+        self.home(&header)
+    }
+
     fn select_element(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
         let args = option.args();
         if args.len() > 0 {
@@ -419,10 +1824,141 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         self.home_headers()
     }
 
+    async fn mark_elements_panel(&self, focus: usize) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_collection() {
+            return self.failed(&error.message());
+        }
+
+        let query = DataBaseQuery::from(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+        );
+
+        let result = self.service.find_all_lite(query).await;
+
+        let header = self.info_headers("Mark or unmark any of the following elements:");
+        if let Err(err) = &result {
+            self.stats.error();
+            return self.failed(&err.to_string());
+        }
+
+        let mut vector = Vec::<String>::new();
+        if result.is_ok() {
+            vector = result.ok().unwrap();
+        }
+
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        for (index, element) in vector.into_iter().enumerate() {
+            let mark = if self.marked.contains(&element) { "[x]" } else { "[ ]" };
+            let args = Vec::from(vec![element.clone(), index.to_string()]);
+            cursor.push(TerminalOption::from_args(
+                format!("{} {}", mark, element),
+                MARK_ELEMENT,
+                args,
+                self.clone(),
+            ));
+        }
+
+        cursor.push(TerminalOption::from(
+            String::from("[Done]"),
+            HOME,
+            self.clone(),
+        ));
+
+        cursor.focus(focus);
+        cursor
+    }
+
+    async fn mark_element(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let args = option.args();
+        let mut focus = 0;
+        if let Some(element) = args.get(0) {
+            if !self.marked.remove(element) {
+                self.marked.insert(element.clone());
+            }
+            focus = args.get(1).and_then(|i| i.parse::<usize>().ok()).unwrap_or(0);
+        }
+
+        self.mark_elements_panel(focus).await
+    }
+
+    fn marked_actions(&self) -> TerminalCursor<Self> {
+        if self.marked.is_empty() {
+            return self.failed("There are no marked elements.");
+        }
+
+        let header = self.info_headers(&format!("{} marked elements, select an action:", self.group_digits(self.marked.len())));
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        cursor.push(TerminalOption::from(String::from("Copy marked elements"), MARKED_COPY, self.clone()));
+        cursor.push(TerminalOption::from(String::from("Export marked elements"), MARKED_EXPORT, self.clone()).require_input_ref());
+        cursor.push(TerminalOption::from(String::from("Clear marks"), CLEAR_MARKS, self.clone()));
+        self.push_back(&mut cursor);
+
+        cursor
+    }
+
+    async fn marked_elements(&self) -> Result<Vec<String>, String> {
+        let filter = FilterElement::from_id_chain_collection(self.marked.iter().cloned().collect());
+        let query = DataBaseQuery::from_filter(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+            filter,
+        );
+
+        match self.service.find_query(query).await {
+            Ok(elements) => Ok(elements),
+            Err(err) => {
+                self.stats.error();
+                Err(err.to_string())
+            }
+        }
+    }
+
+    async fn marked_copy(&self) -> TerminalCursor<Self> {
+        let elements = match self.marked_elements().await {
+            Ok(elements) => elements,
+            Err(error) => return self.failed(&error),
+        };
+
+        terminal_manager::copy_to_clipboard(&elements.join("\n"));
+
+        let header = self.info_headers(&format!("{} marked elements copied to the clipboard.", self.group_digits(elements.len())));
+        self.home(&header)
+    }
+
+    async fn marked_export(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let path = option.args().first().map(|p| String::from(p.trim())).unwrap_or_default();
+        if path.is_empty() {
+            return self.failed("Export cancelled, no path given.");
+        }
+
+        let elements = match self.marked_elements().await {
+            Ok(elements) => elements,
+            Err(error) => return self.failed(&error),
+        };
+
+        let header = match fs::write(&path, self.ndjson(&elements)) {
+            Ok(()) => self.info_headers(&format!("{} marked elements exported to '{}'.", self.group_digits(elements.len()), path)),
+            Err(error) => {
+                self.stats.error();
+                return self.failed(&format!("Cannot export marked elements to '{}': {}", path, error));
+            }
+        };
+
+        self.home(&header)
+    }
+
+    fn clear_marks(&mut self) -> TerminalCursor<Self> {
+        let marks = self.marked.len();
+        self.marked.clear();
+        self.home(&self.info_headers(&format!("{} marks cleared.", self.group_digits(marks))))
+    }
+
     async fn show_selected(&self) -> TerminalCursor<Self> {
         if let Err(error) = self.verify_element() {
-            let header = self.info_headers(&error.message());
-            return self.home(&header);
+            return self.failed(&error.message());
         }
 
         let filter = FilterElement::from_id_chain_collection(self.element.clone().unwrap());
@@ -434,34 +1970,495 @@ impl<T: IDBRepository> ManagerDatabase<T> {
 
         let r_elements = self.service.find_query(query).await;
         if r_elements.is_err() {
-            let header = self.info_headers(&format!(
+            self.stats.error();
+            return self.failed(&format!(
                 "Cannot find enlement: {}",
                 r_elements.unwrap_err().to_string()
             ));
-            return self.home(&header);
         }
 
         let mut elements = r_elements.unwrap();
 
         if elements.len() == 1 {
+            let element = elements.remove(0);
             let header = self.info_headers("Item:");
-            return self.home(&format!("{}\n\n{}", header, elements.remove(0)));
+            return self.home(&format!("{}\n\n{}\n\n{}", header, self.id_line(&element), element));
         }
 
-        elements = elements
-            .iter()
-            .map(|e| {
-                format!(
-                    " {}{}{}",
+        let header = self.info_headers("Items:");
+        if self.sw_compact {
+            let width = terminal_manager::terminal_width().saturating_sub(3);
+            let elements = elements
+                .into_iter()
+                .map(|e| terminal_manager::single_line(&e, width));
+            return self.home(&self.render_items(&header, elements, " - ", "\n"));
+        }
+
+        let header = self.info_headers(&format!(
+            "{} items, move through the list to see each one:",
+            self.group_digits(elements.len())
+        ));
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        let width = terminal_manager::terminal_width();
+        for element in elements {
+            cursor.push(TerminalOption::from_args(
+                terminal_manager::single_line(&element, width),
+                SELECTED_ITEM,
+                Vec::from(vec![element]),
+                self.clone(),
+            ));
+        }
+
+        self.push_back(&mut cursor);
+
+        cursor
+    }
+
+    async fn export_filtered(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_element() {
+            return self.failed(&error.message());
+        }
+
+        let path = option.args().first().map(|p| String::from(p.trim())).unwrap_or_default();
+        if path.is_empty() {
+            return self.failed("Export cancelled, no path given.");
+        }
+
+        let ids = self.element.clone().unwrap();
+        let filter = FilterElement::from_id_chain_collection(ids.clone());
+        let query = DataBaseQuery::from_filter(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+            filter,
+        );
+
+        let documents = match self.service.find_query(query).await {
+            Ok(documents) => documents,
+            Err(err) => {
+                self.stats.error();
+                return self.failed(&err.to_string());
+            }
+        };
+
+        let header = match fs::write(&path, self.ndjson(&documents)) {
+            Ok(()) => self.info_headers(&format!(
+                "{} items exported to '{}', filtered by _id in [{}].",
+                self.group_digits(documents.len()),
+                path,
+                ids.join(", ")
+            )),
+            Err(error) => {
+                self.stats.error();
+                return self.failed(&format!("Cannot export items to '{}': {}", path, error));
+            }
+        };
+
+        self.home(&header)
+    }
+
+    fn selected_item(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let element = option.args().first().cloned().unwrap_or_default();
+        let header = self.info_headers("Item:");
+        self.home(&format!("{}\n\n{}\n\n{}", header, self.id_line(&element), element))
+    }
+
+    fn document_id(&self, document: &Value) -> Option<String> {
+        document.get("_id").cloned().map(|id| match id {
+            Value::String(id) => id,
+            Value::Object(map) if map.contains_key("$oid") => match map.get("$oid") {
+                Some(Value::String(oid)) => oid.clone(),
+                _ => Value::Object(map).to_string(),
+            },
+            id => id.to_string(),
+        })
+    }
+
+    fn id_line(&self, document: &str) -> String {
+        let id = serde_json::from_str::<Value>(document)
+            .ok()
+            .and_then(|document| self.document_id(&document))
+            .unwrap_or(String::from("(unknown)"));
+
+        let mut line = format!(
+            "{}{}_id: {}{}",
+            terminal_manager::ANSI_COLOR_CYAN,
+            terminal_manager::ANSI_BOLD,
+            id,
+            terminal_manager::ANSI_RESET
+        );
+
+        if let Some(modified) = self.modified_at(document) {
+            line = format!(
+                "{} {}modified: {}{}",
+                line,
+                terminal_manager::ANSI_COLOR_YELLOW,
+                modified,
+                terminal_manager::ANSI_RESET
+            );
+        }
+
+        line
+    }
+
+    fn modified_at(&self, document: &str) -> Option<String> {
+        let document = serde_json::from_str::<Value>(document).ok()?;
+        let value = MODIFIED_FIELDS.iter().find_map(|field| document.get(*field))?;
+
+        let value = match value {
+            Value::Object(map) if map.contains_key("$date") => map.get("$date")?.clone(),
+            value => value.clone(),
+        };
+
+        match value {
+            Value::String(date) => Some(date),
+            Value::Object(map) if map.contains_key("$numberLong") => map.get("$numberLong").map(|n| n.to_string().replace("\"", "")),
+            Value::Null => None,
+            value => Some(value.to_string()),
+        }
+    }
+
+    async fn tree_view(&mut self) -> TerminalCursor<Self> {
+        if let Err(error) = self.verify_element() {
+            return self.failed(&error.message());
+        }
+
+        let filter = FilterElement::from_id_chain_collection(self.element.clone().unwrap());
+        let query = DataBaseQuery::from_filter(
+            self.data_base.clone().unwrap(),
+            self.collection.clone().unwrap(),
+            filter,
+        );
+
+        let r_elements = self.service.find_query(query).await;
+        if r_elements.is_err() {
+            self.stats.error();
+            return self.failed(&format!(
+                "Cannot find enlement: {}",
+                r_elements.unwrap_err().to_string()
+            ));
+        }
+
+        let mut documents = Vec::<serde_json::Value>::new();
+        for element in r_elements.unwrap() {
+            match serde_json::from_str::<serde_json::Value>(&element) {
+                Ok(document) => documents.push(document),
+                Err(error) => {
+                    return self.failed(&format!("Cannot parse element: {}", error));
+                }
+            }
+        }
+
+        let document = match documents.len() {
+            1 => documents.remove(0),
+            _ => serde_json::Value::Array(documents),
+        };
+
+        self.tree = Some(TreeView::new(document));
+        self.tree_panel("")
+    }
+
+    fn tree_toggle(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let path = option.args().first().cloned().unwrap_or_default();
+        if let Some(tree) = self.tree.as_mut() {
+            tree.toggle(&path);
+        }
+        self.tree_panel(&path)
+    }
+
+    fn tree_panel(&self, focus: &str) -> TerminalCursor<Self> {
+        let tree = match &self.tree {
+            Some(tree) => tree,
+            None => return self.home_headers(),
+        };
+
+        let header = self.info_headers("Tree view, press Enter to expand or collapse a node:");
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        let mut focused = None;
+        for (position, node) in tree.nodes().into_iter().enumerate() {
+            if node.path == focus {
+                focused = Some(position);
+            }
+
+            let args = Vec::from(vec![node.path]);
+            cursor.push(TerminalOption::from_args(
+                node.title,
+                TREE_TOGGLE,
+                args,
+                self.clone(),
+            ));
+        }
+
+        self.push_back(&mut cursor);
+        if let Some(position) = focused {
+            let offset = if TerminalConfiguration::is_none_last() { 0 } else { 1 };
+            cursor.focus(position + offset);
+        }
+
+        cursor
+    }
+
+    fn toggle_compact(&mut self) -> TerminalCursor<Self> {
+        self.sw_compact = !self.sw_compact;
+
+        let mut header = self.info_headers("Selected items will be listed to browse, with the focused one in the detail pane.");
+        if self.sw_compact {
+            header = self.info_headers("Selected items will be shown one per line, without the detail pane.");
+        }
+
+        self.home(&header)
+    }
+
+    fn reset_stats(&self) -> TerminalCursor<Self> {
+        self.stats.reset();
+        self.home(&self.info_headers("Session stats cleared."))
+    }
+
+    fn clear_cache(&self) -> TerminalCursor<Self> {
+        let mut cache = self.preview_cache.lock().unwrap();
+        let previews = cache.len();
+        cache.clear();
+        drop(cache);
+
+        let header = self.info_headers(&format!("Cache cleared, {} collection previews dropped.", self.group_digits(previews)));
+        self.home(&header)
+    }
+
+    fn copy_connection(&self) -> TerminalCursor<Self> {
+        let connection = match &self.connection {
+            Some(connection) => connection,
+            None => return self.failed("No connection details available."),
+        };
+
+        let json = connection.to_redacted_json();
+        terminal_manager::copy_to_clipboard(&json);
+
+        let header = self.info_headers("Connection details copied to the clipboard, password redacted:");
+        self.home(&format!("{}\n\n{}", header, json))
+    }
+
+    fn is_batchable(&self, option: &TerminalOption<Self>, command: &Command) -> bool {
+        if self.is_confirmed(option) {
+            return false;
+        }
+        command.category() == ECommandCategory::Write || command.is_destructive()
+    }
+
+    fn start_batch(&mut self) -> TerminalCursor<Self> {
+        if self.batch.is_none() {
+            self.batch = Some(Vec::new());
+        }
+        self.home(&self.info_headers("Batch mode on, commands are queued until the batch is run or cleared."))
+    }
+
+    fn queue_batch(&mut self, label: &str, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let entry = BatchEntry {
+            label: String::from(label),
+            option: option.option(),
+            args: option.args(),
+            data_base: self.data_base.clone(),
+            collection: self.collection.clone(),
+            element: self.element.clone(),
+        };
+
+        let batch = self.batch.get_or_insert_with(Vec::new);
+        batch.push(entry);
+        let queued = batch.len();
+
+        let header = self.info_headers(&format!("'{}' queued, {} commands in the batch.", label, self.group_digits(queued)));
+        self.home(&header)
+    }
+
+    async fn run_batch(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let batch = self.batch.clone().unwrap_or_default();
+        if batch.is_empty() {
+            return self.home(&self.info_headers("There are no queued commands to run."));
+        }
+
+        if !self.is_confirmed(&option) {
+            let mut destructive = false;
+            let mut queued = Vec::<String>::new();
+            for entry in &batch {
+                let target = [entry.data_base.clone(), entry.collection.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<String>>()
+                    .join(" > ");
+                let mut line = format!("{} ({})", entry.label, target);
+                if target.is_empty() {
+                    line = entry.label.clone();
+                }
+
+                if CommandRegistry::find(&entry.option).map(|c| c.is_destructive()).unwrap_or(false) {
+                    destructive = true;
+                    line = format!("{}{}{}", terminal_manager::ANSI_COLOR_RED, line, terminal_manager::ANSI_RESET);
+                }
+                queued.push(line);
+            }
+
+            let mut message = format!("Run the {} queued commands in order?", self.group_digits(batch.len()));
+            if self.sw_protected && destructive {
+                message = format!(
+                    "{}{}[PRODUCTION]{} {}",
+                    terminal_manager::ANSI_COLOR_RED,
                     terminal_manager::ANSI_BOLD,
-                    e,
-                    terminal_manager::ANSI_RESET
-                )
+                    terminal_manager::ANSI_RESET,
+                    message
+                );
+            }
+
+            return self.confirm_panel(&self.render_items(&message, queued, " - ", "\n"), option);
+        }
+
+        self.batch = None;
+
+        let mut manager = self.clone();
+        let mut lines = Vec::<String>::new();
+        let mut failures = 0;
+        for entry in batch {
+            manager.data_base = entry.data_base.clone();
+            manager.collection = entry.collection.clone();
+            manager.element = entry.element.clone();
+            manager.failure = None;
+
+            let mut option = TerminalOption::from_args(entry.label.clone(), &entry.option, entry.args.clone(), manager.clone());
+            option.confirmed();
+
+            // Boxed, the batch replays through manage recursively.
+            let cursor = Box::pin(manager.manage(option)).await;
+            manager = cursor.manager();
+
+            match manager.failure.take() {
+                Some(failure) => {
+                    failures = failures + 1;
+                    lines.push(format!(
+                        "{}{}: failed ({}){}",
+                        terminal_manager::ANSI_COLOR_RED,
+                        entry.label,
+                        failure,
+                        terminal_manager::ANSI_RESET
+                    ));
+                }
+                None => lines.push(format!("{}{}: done{}", terminal_manager::ANSI_COLOR_GREEN, entry.label, terminal_manager::ANSI_RESET)),
+            }
+        }
+
+        let header = manager.info_headers(&format!(
+            "Batch finished, {} of {} commands failed:",
+            manager.group_digits(failures),
+            manager.group_digits(lines.len())
+        ));
+        manager.home(&manager.render_items(&header, lines, " - ", "\n"))
+    }
+
+    fn clear_batch(&mut self) -> TerminalCursor<Self> {
+        let queued = self.batch.take().map(|b| b.len()).unwrap_or(0);
+        self.home(&self.info_headers(&format!("Batch mode off, {} queued commands dropped.", self.group_digits(queued))))
+    }
+
+    fn toggle_menu_mode(&mut self) -> TerminalCursor<Self> {
+        self.sw_advanced = !self.sw_advanced;
+
+        let mut header = self.info_headers("Showing the basic commands only.");
+        if self.sw_advanced {
+            header = self.info_headers("Showing every command.");
+        }
+
+        self.home(&header)
+    }
+
+    fn config_path(&self) -> TerminalCursor<Self> {
+        let lines = TerminalConfiguration::keys()
+            .iter()
+            .map(|key| {
+                let value = TerminalConfiguration::variable(key).unwrap_or(String::from("(unset)"));
+                format!("{}: {}", key, value)
             })
             .collect::<Vec<String>>();
 
-        let header = self.info_headers("Items:");
-        self.home(&format!("{}\n\n{}", header, elements.join("\n\n")))
+        let saved = match SavedQueries::path() {
+            Some(path) if Path::new(&path).exists() => format!("Saved queries are stored in '{}'.", path),
+            Some(path) => format!("Saved queries will be stored in '{}', the file does not exist yet.", path),
+            None => format!(
+                "Saved queries are kept for this session only, set {} to store them.",
+                terminal_configuration::SAVED_QUERIES
+            ),
+        };
+
+        let header = self.info_headers("Settings are read from the environment:");
+        self.home(&format!("{}\n\n{}", self.render_items(&header, lines, " - ", "\n"), saved))
+    }
+
+    fn save_query(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let input = option.args().first().cloned().unwrap_or_default();
+        let (name, query) = match input.trim().split_once(char::is_whitespace) {
+            Some((name, query)) if !query.trim().is_empty() => (String::from(name), String::from(query.trim())),
+            _ => return self.failed("Invalid query, expected '<name> <query path>'."),
+        };
+
+        self.saved_queries.insert(name.clone(), query);
+        let header = match SavedQueries::store(&self.saved_queries) {
+            Ok(true) => self.info_headers(&format!("Query '{}' saved.", name)),
+            Ok(false) => self.info_headers(&format!("Query '{}' saved for this session.", name)),
+            Err(error) => {
+                self.stats.error();
+                return self.failed(&error);
+            }
+        };
+
+        self.home(&header)
+    }
+
+    fn show_saved(&self) -> TerminalCursor<Self> {
+        if self.saved_queries.is_empty() {
+            return self.home(&self.info_headers("There are no saved queries."));
+        }
+
+        let header = self.info_headers("Select a saved query to run:");
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        for (name, query) in &self.saved_queries {
+            cursor.push(TerminalOption::from_args(
+                format!("{}: {}", name, query),
+                RUN_SAVED,
+                Vec::from(vec![name.clone()]),
+                self.clone(),
+            ));
+        }
+
+        cursor.push(TerminalOption::from(String::from("Delete a saved query"), DELETE_SAVED, self.clone()).require_input_ref());
+        self.push_back(&mut cursor);
+
+        cursor
+    }
+
+    async fn run_saved(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let name = option.args().first().map(|n| String::from(n.trim())).unwrap_or_default();
+        let query = match self.saved_queries.get(&name) {
+            Some(query) => query.clone(),
+            None => return self.failed(&format!("No saved query named '{}'.", name)),
+        };
+
+        let option = TerminalOption::from_input(Vec::from(vec![query]), self.clone());
+        self.translate_query(option).await
+    }
+
+    fn delete_saved(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let name = option.args().first().map(|n| String::from(n.trim())).unwrap_or_default();
+        if self.saved_queries.remove(&name).is_none() {
+            return self.failed(&format!("No saved query named '{}'.", name));
+        }
+
+        let header = match SavedQueries::store(&self.saved_queries) {
+            Ok(_) => self.info_headers(&format!("Query '{}' deleted.", name)),
+            Err(error) => {
+                self.stats.error();
+                return self.failed(&error);
+            }
+        };
+
+        self.home(&header)
     }
 
     async fn translate_query(&mut self, option: TerminalOption<Self>) -> TerminalCursor<Self> {
@@ -484,4 +2481,103 @@ impl<T: IDBRepository> ManagerDatabase<T> {
 
         return self.home_headers();
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::manager::{
+        data_base::test_repository::TestRepository,
+        i_manager::IManager,
+        terminal_manager,
+        terminal_option::TerminalOption,
+    };
+
+    use super::{ManagerDatabase, CREATE_DATABASE, DOCUMENT_HISTORY, DROP_DATABASE, FIND_PAGE, RUN_BATCH, START_BATCH};
+
+    fn find_page(manager: &ManagerDatabase<TestRepository>, bounds: &str) -> TerminalOption<ManagerDatabase<TestRepository>> {
+        TerminalOption::from_args(String::new(), FIND_PAGE, Vec::from(vec![String::from(bounds)]), manager.clone())
+    }
+
+    #[tokio::test]
+    async fn find_page_preserves_the_last_page_when_the_connection_drops() {
+        let elements = (1..=30).map(|index| index.to_string()).collect::<Vec<String>>();
+        let repository = TestRepository::new(elements);
+        let manager = repository.manager();
+
+        let cursor = manager.manage(find_page(&manager, "10")).await;
+        let manager = cursor.manager();
+        assert!(manager.page.is_some());
+
+        repository.fail(true);
+        let mut cursor = manager.manage(find_page(&manager, "10 10")).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Connection lost: Connection reset by peer."));
+        assert!(header.contains("Last page preserved:"));
+        assert!(header.lines().any(|line| line == " - 1"));
+        assert!(header.lines().any(|line| line == " - 10"));
+        assert!(!header.lines().any(|line| line == " - 11"));
+
+        let options = cursor.options();
+        assert_eq!(options[0].title(), "Retry");
+        assert_eq!(options[0].option(), FIND_PAGE);
+        assert_eq!(options[0].args(), Vec::from(vec![String::from("10 10")]));
+
+        repository.fail(false);
+        let cursor = manager.manage(options[0].clone()).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Items 11-20 of 30, paged client-side over the full listing:"));
+        assert!(header.lines().any(|line| line == " - 11"));
+    }
+
+    #[tokio::test]
+    async fn run_batch_reports_the_result_of_each_replay() {
+        let elements = (1..=30).map(|index| index.to_string()).collect::<Vec<String>>();
+        let mut manager = TestRepository::new(elements).manager();
+        manager.sw_protected = true;
+
+        let start = TerminalOption::from(String::new(), START_BATCH, manager.clone());
+        let manager = manager.manage(start).await.manager();
+
+        let cursor = manager.manage(find_page(&manager, "10")).await;
+        assert!(terminal_manager::strip_ansi(&cursor.header()).contains("Items 1-10 of 30, paged client-side over the full listing:"));
+
+        let mut manager = cursor.manager();
+        let queued = Vec::from(vec![
+            TerminalOption::from_args(String::new(), CREATE_DATABASE, Vec::from(vec![String::from("stock")]), manager.clone()),
+            TerminalOption::from(String::new(), DROP_DATABASE, manager.clone()),
+            TerminalOption::from(String::new(), DROP_DATABASE, manager.clone()),
+        ]);
+        for option in queued {
+            manager = manager.manage(option).await.manager();
+        }
+
+        let run = TerminalOption::from(String::new(), RUN_BATCH, manager.clone());
+        let mut cursor = manager.manage(run).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("[PRODUCTION] Run the 3 queued commands in order?"));
+        assert_eq!(header.lines().filter(|line| line.ends_with("(shop > orders)")).count(), 3);
+
+        let confirm = cursor.options()[1].clone();
+        let cursor = cursor.manager().manage(confirm).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Batch finished, 1 of 3 commands failed:"));
+        assert_eq!(header.lines().filter(|line| line.ends_with(": done")).count(), 2);
+        assert_eq!(header.lines().filter(|line| line.contains(": failed (")).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn unsupported_commands_explain_why() {
+        let manager = TestRepository::new(Vec::new()).manager();
+
+        let option = TerminalOption::from(String::new(), DOCUMENT_HISTORY, manager.clone());
+        let cursor = manager.manage(option).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("'Document history' is unsupported by the core: it exposes no versioned or point-in-time reads."));
+        assert!(cursor.manager().failure.is_some());
+    }
+
+}
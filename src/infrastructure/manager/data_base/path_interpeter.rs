@@ -6,6 +6,16 @@ use super::manager_database::ManagerDatabase;
 impl <T: IDBRepository> ManagerDatabase<T> {
     
     pub async fn translate_path(&mut self, first: String, fragments: Vec<String>) -> TerminalCursor<Self> {
+        match self.interpret_path(first, fragments).await {
+            Ok(cursor) => cursor,
+            Err(message) => {
+                self.stats.error();
+                self.home(&self.info_headers(&message))
+            }
+        }
+    }
+
+    pub async fn interpret_path(&mut self, first: String, fragments: Vec<String>) -> Result<TerminalCursor<Self>, String> {
         if first == "*" {
             return self.translate_query_path(fragments, false).await;
         }
@@ -13,31 +23,31 @@ impl <T: IDBRepository> ManagerDatabase<T> {
         return self.translate_query_path(fragments, true).await;
     }
 
-    async fn translate_query_path(&mut self, fragments: Vec<String>, sw_relative: bool) -> TerminalCursor<Self> {
+    async fn translate_query_path(&mut self, fragments: Vec<String>, sw_relative: bool) -> Result<TerminalCursor<Self>, String> {
         let (fragment, fragments) = self.first_fragment(fragments);
-        let result = self.translate_query_path_database(fragment, fragments.clone(), sw_relative).await;
+        let result = self.translate_query_path_database(fragment, fragments.clone(), sw_relative).await?;
         if result.is_some() {
-            return result.unwrap();
+            return Ok(result.unwrap());
         }
 
         let (fragment, fragments) = self.first_fragment(fragments);
-        let result = self.translate_query_path_collection(fragment, fragments.clone(), sw_relative).await;
+        let result = self.translate_query_path_collection(fragment, fragments.clone(), sw_relative).await?;
         if result.is_some() {
-            return result.unwrap();
+            return Ok(result.unwrap());
         }
 
         let (fragment, fragments) = self.first_fragment(fragments);
-        let result = self.translate_query_path_elements(fragment, fragments.clone(), sw_relative).await;
+        let result = self.translate_query_path_elements(fragment, fragments.clone(), sw_relative).await?;
         if result.is_some() {
-            return result.unwrap();
+            return Ok(result.unwrap());
         }
 
         let (fragment, _) = self.first_fragment(fragments);
         if fragment.is_some() {
-            return self.home(&self.info_headers("Cannot undertand query extra parameters detected."));
+            return Err(String::from("Cannot undertand query extra parameters detected."));
         }
 
-        return self.home_headers();
+        return Ok(self.home_headers());
     }
 
     fn first_fragment(&self, mut fragments: Vec<String>) -> (Option<String>, Vec<String>) {
@@ -47,64 +57,71 @@ impl <T: IDBRepository> ManagerDatabase<T> {
         return (None, fragments);
     }
 
-    async fn translate_query_path_database(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Option<TerminalCursor<Self>> {
+    async fn translate_query_path_database(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Result<Option<TerminalCursor<Self>>, String> {
         if o_fragment.is_none() {
             self.reset_database();
-            return Some(self.home_headers());
+            return Ok(Some(self.home_headers()));
         }
 
         let fragment = o_fragment.clone().unwrap();
 
         if let Ok(result) = self.translate_query_path_back(fragment.clone(), fragments.clone()) {
-            return result; 
+            return Ok(result); 
         }
 
         if !sw_relative || (sw_relative && self.data_base.is_none()) {
-            self.data_base = Some(fragment);
+            if self.data_base.as_ref() != Some(&fragment) {
+                self.reset_database();
+            }
+            self.data_base = Some(fragment.clone());
             let result = self.valide_data_base_connection().await;
             if result.is_err() {
                 self.reset_database();
-                return Some(self.home(&self.info_headers(&result.unwrap_err().message())));
+                return Err(result.unwrap_err().message());
             }
-            return None;
+            self.record_database_access(fragment);
+            return Ok(None);
         }
         
         self.translate_query_path_collection(o_fragment, fragments, sw_relative).await
     }
 
-    async fn translate_query_path_collection(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Option<TerminalCursor<Self>> {        
+    async fn translate_query_path_collection(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Result<Option<TerminalCursor<Self>>, String> {        
         if o_fragment.is_none() {
-            return Some(self.home_headers());
+            return Ok(Some(self.home_headers()));
         }
         
         let fragment = o_fragment.clone().unwrap();
 
         if let Ok(result) = self.translate_query_path_back(fragment.clone(), fragments.clone()) {
-            return result; 
+            return Ok(result); 
         }
         
         if !sw_relative || (sw_relative && self.collection.is_none()) {
+            if self.collection.as_ref() != Some(&fragment) {
+                self.reset_collection();
+            }
             self.collection = Some(fragment);
             let result = self.valide_collection_connection().await;
             if result.is_err() {
                 self.reset_collection();
-                return Some(self.home(&self.info_headers(&result.unwrap_err().message())));
+                return Err(result.unwrap_err().message());
             }
-            return None;
+            return Ok(None);
         }
 
         self.translate_query_path_elements(o_fragment, fragments, sw_relative).await
     }
 
-    async fn translate_query_path_elements(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Option<TerminalCursor<Self>> {
+    async fn translate_query_path_elements(&mut self, o_fragment: Option<String>, fragments: Vec<String>, sw_relative: bool) -> Result<Option<TerminalCursor<Self>>, String> {
         if o_fragment.is_none() {
-            return Some(self.home_headers());
+            return Ok(Some(self.home_headers()));
         }
 
         let fragment = o_fragment.unwrap();
 
         if let Ok(result) = self.translate_query_path_back(fragment.clone(), fragments) {
-            return result; 
+            return Ok(result); 
         }
 
         if !sw_relative || (sw_relative && self.element.is_none()) {
@@ -116,11 +133,11 @@ impl <T: IDBRepository> ManagerDatabase<T> {
             let result = self.valide_element_connection().await;
             if result.is_err() {
                 self.reset_collection();
-                return Some(self.home(&self.info_headers(&result.unwrap_err().message())));
+                return Err(result.unwrap_err().message());
             }
         }
 
-        None
+        Ok(None)
     }
 
     fn translate_query_path_back(&mut self, fragment: String, fragments: Vec<String>) -> Result<Option<TerminalCursor<Self>>, ()> {
@@ -157,4 +174,54 @@ impl <T: IDBRepository> ManagerDatabase<T> {
         return result; 
     }
 
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::manager::data_base::test_repository::{self, TestRepository};
+
+    fn path(fragments: &[&str]) -> Vec<String> {
+        fragments.iter().map(|f| String::from(*f)).collect::<Vec<String>>()
+    }
+
+    #[tokio::test]
+    async fn changing_the_collection_by_path_drops_marks_and_page() {
+        let mut manager = TestRepository::new(Vec::new()).manager();
+        manager.marked.insert(String::from("1"));
+        manager.page = Some(String::from(" - 1"));
+
+        let result = manager.interpret_path(String::from("*"), path(&[test_repository::DATA_BASE, "invoices"])).await;
+
+        assert!(result.is_ok());
+        assert_eq!(manager.collection.as_deref(), Some("invoices"));
+        assert!(manager.marked.is_empty());
+        assert!(manager.page.is_none());
+    }
+
+    #[tokio::test]
+    async fn changing_the_data_base_by_path_drops_the_collection_state() {
+        let mut manager = TestRepository::new(Vec::new()).manager();
+        manager.marked.insert(String::from("1"));
+        manager.page = Some(String::from(" - 1"));
+
+        let result = manager.interpret_path(String::from("*"), path(&["archive"])).await;
+
+        assert!(result.is_ok());
+        assert_eq!(manager.data_base.as_deref(), Some("archive"));
+        assert!(manager.collection.is_none());
+        assert!(manager.marked.is_empty());
+        assert!(manager.page.is_none());
+    }
+
+    #[tokio::test]
+    async fn selecting_the_same_collection_by_path_keeps_marks() {
+        let mut manager = TestRepository::new(Vec::new()).manager();
+        manager.marked.insert(String::from("1"));
+
+        let fragments = path(&[test_repository::DATA_BASE, test_repository::COLLECTION]);
+        let result = manager.interpret_path(String::from("*"), fragments).await;
+
+        assert!(result.is_ok());
+        assert!(manager.marked.contains("1"));
+    }
+
+}
@@ -0,0 +1,65 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct Schema {
+    fields: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Schema {
+
+    pub fn infer(documents: &[String]) -> Schema {
+        let mut schema = Schema::default();
+        for document in documents {
+            if let Ok(value) = serde_json::from_str::<Value>(document) {
+                schema.collect("", &value);
+            }
+        }
+        schema
+    }
+
+    pub fn fields(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.fields
+    }
+
+    pub fn types(&self, field: &str) -> Option<&BTreeSet<String>> {
+        self.fields.get(field)
+    }
+
+    fn collect(&mut self, prefix: &str, value: &Value) {
+        let map = match value {
+            Value::Object(map) => map,
+            _ => return,
+        };
+
+        for (key, value) in map {
+            let field = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+
+            self.fields
+                .entry(field.clone())
+                .or_default()
+                .insert(String::from(Schema::type_name(value)));
+
+            if value.is_object() {
+                self.collect(&field, value);
+            }
+        }
+    }
+
+    pub fn type_name(value: &Value) -> &'static str {
+        match value {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+}
@@ -0,0 +1,133 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use rust_db_manager_core::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{filter::data_base_query::DataBaseQuery, generate::generate_database_query::GenerateDatabaseQuery},
+    infrastructure::repository::i_db_repository::IDBRepository,
+    service::service::Service,
+};
+
+use super::manager_database::ManagerDatabase;
+
+pub const DATA_BASE: &'static str = "shop";
+pub const COLLECTION: &'static str = "orders";
+
+#[derive(Clone)]
+pub struct TestRepository {
+    state: Arc<Mutex<TestState>>,
+}
+
+struct TestState {
+    sw_data_base: bool,
+    sw_collection: bool,
+    sw_failing: bool,
+    elements: Vec<String>,
+}
+
+impl TestRepository {
+
+    pub fn new(elements: Vec<String>) -> TestRepository {
+        TestRepository {
+            state: Arc::new(Mutex::new(TestState {
+                sw_data_base: true,
+                sw_collection: true,
+                sw_failing: false,
+                elements,
+            })),
+        }
+    }
+
+    pub fn manager(&self) -> ManagerDatabase<TestRepository> {
+        let mut manager = ManagerDatabase::new(Service::from(self.clone()));
+        manager.data_base = Some(String::from(DATA_BASE));
+        manager.collection = Some(String::from(COLLECTION));
+        manager
+    }
+
+    pub fn remove_data_base(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.sw_data_base = false;
+        state.sw_collection = false;
+    }
+
+    pub fn remove_collection(&self) {
+        self.state.lock().unwrap().sw_collection = false;
+    }
+
+    pub fn fail(&self, sw_failing: bool) {
+        self.state.lock().unwrap().sw_failing = sw_failing;
+    }
+
+    fn check(&self) -> Result<(), ConnectException> {
+        if self.state.lock().unwrap().sw_failing {
+            return Err(ConnectException::new(String::from("Connection reset by peer.")));
+        }
+        Ok(())
+    }
+
+}
+
+#[async_trait]
+impl IDBRepository for TestRepository {
+
+    async fn status(&self) -> Result<(), ConnectException> {
+        self.check()
+    }
+
+    async fn data_base_exists(&self, _query: DataBaseQuery) -> Result<bool, ConnectException> {
+        self.check()?;
+        Ok(self.state.lock().unwrap().sw_data_base)
+    }
+
+    async fn collection_exists(&self, _query: DataBaseQuery) -> Result<bool, ConnectException> {
+        self.check()?;
+        Ok(self.state.lock().unwrap().sw_collection)
+    }
+
+    async fn list_data_bases(&self) -> Result<Vec<String>, ConnectException> {
+        self.check()?;
+        let mut data_bases = Vec::<String>::new();
+        if self.state.lock().unwrap().sw_data_base {
+            data_bases.push(String::from(DATA_BASE));
+        }
+        Ok(data_bases)
+    }
+
+    async fn list_collections(&self, _query: DataBaseQuery) -> Result<Vec<String>, ConnectException> {
+        self.check()?;
+        let mut collections = Vec::<String>::new();
+        if self.state.lock().unwrap().sw_collection {
+            collections.push(String::from(COLLECTION));
+        }
+        Ok(collections)
+    }
+
+    async fn create_data_base(&self, _query: GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        self.check()?;
+        Ok(String::from(DATA_BASE))
+    }
+
+    async fn drop_data_base(&self, _query: GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        self.check()?;
+        self.remove_data_base();
+        Ok(String::from(DATA_BASE))
+    }
+
+    async fn find(&self, _query: DataBaseQuery) -> Result<Option<String>, ConnectException> {
+        self.check()?;
+        Ok(self.state.lock().unwrap().elements.first().cloned())
+    }
+
+    async fn find_query(&self, _query: DataBaseQuery) -> Result<Vec<String>, ConnectException> {
+        self.check()?;
+        Ok(self.state.lock().unwrap().elements.clone())
+    }
+
+    async fn find_all_lite(&self, _query: DataBaseQuery) -> Result<Vec<String>, ConnectException> {
+        self.check()?;
+        Ok(self.state.lock().unwrap().elements.clone())
+    }
+
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+#[derive(Clone)]
+pub struct TreeView {
+    document: Value,
+    expanded: HashSet<String>,
+}
+
+#[derive(Clone)]
+pub struct TreeNode {
+    pub path: String,
+    pub title: String,
+    pub container: bool,
+}
+
+impl TreeView {
+
+    pub fn new(document: Value) -> TreeView {
+        let mut expanded = HashSet::new();
+        expanded.insert(String::new());
+        TreeView { document, expanded }
+    }
+
+    pub fn toggle(&mut self, path: &str) -> &Self {
+        if !self.nodes().iter().any(|n| n.container && n.path == path) {
+            return self;
+        }
+        if !self.expanded.remove(path) {
+            self.expanded.insert(String::from(path));
+        }
+        self
+    }
+
+    pub fn nodes(&self) -> Vec<TreeNode> {
+        let mut nodes = Vec::new();
+        self.collect(&mut nodes, "", String::new(), &self.document, 0);
+        nodes
+    }
+
+    fn collect(&self, nodes: &mut Vec<TreeNode>, key: &str, path: String, value: &Value, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let mut label = String::from(key);
+        if label.is_empty() {
+            label = String::from("root");
+        }
+
+        let children = match value {
+            Value::Object(map) => map.iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect::<Vec<(String, &Value)>>(),
+            Value::Array(array) => array.iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v))
+                .collect::<Vec<(String, &Value)>>(),
+            _ => {
+                nodes.push(TreeNode {
+                    path,
+                    title: format!("{}  {}: {}", indent, label, value),
+                    container: false,
+                });
+                return;
+            }
+        };
+
+        let (open, close) = if value.is_array() { ("[", "]") } else { ("{", "}") };
+        let expanded = self.expanded.contains(&path);
+        let marker = if expanded { "-" } else { "+" };
+
+        nodes.push(TreeNode {
+            path: path.clone(),
+            title: format!("{}{} {} {}{}{}", indent, marker, label, open, children.len(), close),
+            container: true,
+        });
+
+        if !expanded {
+            return;
+        }
+
+        for (child, value) in children {
+            let child_path = format!("{}/{}", path, child.replace("~", "~0").replace("/", "~1"));
+            self.collect(nodes, &child, child_path, value, depth + 1);
+        }
+    }
+
+}
@@ -1,12 +1,21 @@
+use std::{fmt::Write, time::SystemTime};
+
 use rust_db_manager_core::{
     commons::exception::connect_exception::ConnectException,
     domain::filter::{data_base_query::DataBaseQuery, filter_element::FilterElement},
     infrastructure::repository::i_db_repository::IDBRepository,
 };
 
-use crate::infrastructure::manager::{terminal_cursor::TerminalCursor, terminal_manager, terminal_option::TerminalOption};
+use crate::{
+    commons::configuration::terminal_configuration::TerminalConfiguration,
+    infrastructure::manager::{terminal_cursor::TerminalCursor, terminal_manager, terminal_option::TerminalOption},
+};
 
-use super::manager_database::{self, ManagerDatabase};
+use super::{
+    command::{Command, ESelectionLevel},
+    command_registry::CommandRegistry,
+    manager_database::{self, ManagerDatabase},
+};
 
 impl<T: IDBRepository> ManagerDatabase<T> {
     pub fn default_header(&self) -> String {
@@ -70,76 +79,187 @@ impl<T: IDBRepository> ManagerDatabase<T> {
     pub fn home(&self, header: &str) -> TerminalCursor<Self> {
         let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), header);
 
-        cursor.push(TerminalOption::from(
-            String::from("Status"),
-            manager_database::STATUS,
-            self.clone(),
-        ));
-        cursor.push(
-            TerminalOption::from(
-                String::from("Create database"),
-                manager_database::CREATE_DATABASE,
-                self.clone(),
-            )
-            .require_input_ref(),
-        );
-        cursor.push(TerminalOption::from(
-            String::from("Show databases"),
-            manager_database::SHOW_DATABASES,
-            self.clone(),
-        ));
-        cursor.push(TerminalOption::from(
-            String::from("Select database"),
-            manager_database::SELECT_DATABASE_PANEL,
-            self.clone(),
-        ));
+        for command in CommandRegistry::commands() {
+            if !command.is_menu() || !self.is_available(&command) {
+                continue;
+            }
 
-        if self.data_base.is_some() {
-            cursor.push(TerminalOption::from(
-                String::from("Drop database"),
-                manager_database::DROP_DATABASE,
-                self.clone(),
-            ));
-            cursor.push(TerminalOption::from(
-                String::from("Show collections"),
-                manager_database::SHOW_COLLECTIONS,
-                self.clone(),
-            ));
-            cursor.push(TerminalOption::from(
-                String::from("Select collection"),
-                manager_database::SELECT_COLLECTION_PANEL,
+            let mut option = TerminalOption::from(
+                self.command_label(&command),
+                command.key(),
                 self.clone(),
-            ));
+            );
+            if command.input_required() {
+                option.require_input();
+            }
+
+            cursor.push(option);
         }
 
-        if self.collection.is_some() {
-            cursor.push(TerminalOption::from(
-                String::from("Show elements"),
-                manager_database::SHOW_ELEMENTS,
-                self.clone(),
-            ));
-            cursor.push(TerminalOption::from(
-                String::from("Select element"),
-                manager_database::SELECT_ELEMENTS_PANEL,
-                self.clone(),
-            ));
+        cursor
+    }
+
+    pub fn failed(&self, message: &str) -> TerminalCursor<Self> {
+        self.failed_home(&self.info_headers(message), message)
+    }
+
+    pub fn failed_home(&self, header: &str, failure: &str) -> TerminalCursor<Self> {
+        let mut manager = self.clone();
+        manager.failure = Some(terminal_manager::strip_ansi(failure));
+        manager.home(header)
+    }
+
+    fn is_available(&self, command: &Command) -> bool {
+        if command.key() == manager_database::CLEAR_DATABASE_HISTORY && self.data_base_history.is_empty() {
+            return false;
+        }
+        if command.key() == manager_database::MARKED_ACTIONS && self.marked.is_empty() {
+            return false;
+        }
+        if command.key() == manager_database::DIFF_CHECKPOINT && self.checkpoint.is_none() {
+            return false;
+        }
+        if command.key() == manager_database::START_BATCH && self.batch.is_some() {
+            return false;
+        }
+        if (command.key() == manager_database::RUN_BATCH || command.key() == manager_database::CLEAR_BATCH) && self.batch.is_none() {
+            return false;
         }
+        if !self.sw_advanced && !command.is_basic() {
+            return false;
+        }
+        self.verify_selection(command.selection()).is_ok()
+    }
 
-        if self.element.is_some() {
-            cursor.push(TerminalOption::from(
-                String::from("Show selected"),
-                manager_database::SHOW_SELECTED,
-                self.clone(),
-            ));
+    fn command_label(&self, command: &Command) -> String {
+        if command.key() == manager_database::TOGGLE_DATABASE_RECENCY && self.sw_recency_sort {
+            return String::from("Sort databases alphabetically");
         }
+        if command.key() == manager_database::TOGGLE_COMPACT && self.sw_compact {
+            return String::from("Browse selected items");
+        }
+        if command.key() == manager_database::TOGGLE_FULL_LISTING && self.sw_full_listing {
+            return String::from("List lite elements");
+        }
+        if command.key() == manager_database::TOGGLE_MENU_MODE && self.sw_advanced {
+            return String::from("Show basic commands");
+        }
+        if command.unsupported_reason().is_some() {
+            return format!("{} (unsupported)", command.label());
+        }
+        String::from(command.label())
+    }
 
-        cursor
+    pub fn render_items<I>(&self, header: &str, items: I, bullet: &str, separator: &str) -> String
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut output = String::from(header);
+        output.push('\n');
+
+        for (index, item) in items.into_iter().enumerate() {
+            output.push_str(if index == 0 { "\n" } else { separator });
+            let _ = write!(
+                output,
+                "{}{}{}{}",
+                bullet,
+                terminal_manager::ANSI_BOLD,
+                item,
+                terminal_manager::ANSI_RESET
+            );
+        }
+
+        output
     }
 
     pub fn home_headers(&self) -> TerminalCursor<Self> {
         self.home(&self.default_header())
     }
 
+    pub fn confirm_panel(&self, message: &str, option: TerminalOption<Self>) -> TerminalCursor<Self> {
+        let header = self.info_headers(message);
+        let mut cursor: TerminalCursor<Self> = TerminalCursor::new(self.clone(), &header);
+
+        cursor.push(TerminalOption::from(
+            String::from("No, go back"),
+            manager_database::HOME,
+            self.clone(),
+        ));
+        let mut confirm = TerminalOption::from_args(
+            String::from("Yes, continue"),
+            &option.option(),
+            option.args(),
+            self.clone(),
+        );
+        confirm.confirmed();
+        cursor.push(confirm);
+
+        cursor
+    }
+
+    pub fn push_none(&self, cursor: &mut TerminalCursor<Self>, option: &str) {
+        self.push_exit(cursor, "[None]", option);
+    }
+
+    pub fn push_back(&self, cursor: &mut TerminalCursor<Self>) {
+        self.push_exit(cursor, "[Back]", manager_database::HOME);
+    }
+
+    fn push_exit(&self, cursor: &mut TerminalCursor<Self>, title: &str, option: &str) {
+        let mut exit = TerminalOption::from(String::from(title), option, self.clone());
+        if TerminalConfiguration::is_none_last() {
+            cursor.push(exit);
+            return;
+        }
+
+        exit.separated();
+        cursor.push_front(exit);
+        cursor.focus(1);
+    }
+
+    pub fn wipe_preview(&self, key: &str, option: &TerminalOption<Self>) -> String {
+        let names = |names: Vec<String>| {
+            if names.is_empty() {
+                return String::new();
+            }
+            format!(": {}", names.join(", "))
+        };
+
+        match key {
+            manager_database::CLEAR_DATABASE_HISTORY => {
+                let mut data_bases = self.data_base_history.keys().cloned().collect::<Vec<String>>();
+                data_bases.sort();
+                format!("Forget {} accessed data bases{}?", self.group_digits(data_bases.len()), names(data_bases))
+            }
+            manager_database::DELETE_SAVED => {
+                let name = option.args().first().map(|n| String::from(n.trim())).unwrap_or_default();
+                match self.saved_queries.get(&name) {
+                    Some(query) => format!("Delete the saved query '{}': {}?", name, query),
+                    None => format!("Delete the saved query '{}'?", name),
+                }
+            }
+            manager_database::CLEAR_MARKS => {
+                let marks = self.marked.iter().cloned().collect::<Vec<String>>();
+                format!("Clear {} marks{}?", self.group_digits(marks.len()), names(marks))
+            }
+            manager_database::CLEAR_BATCH => {
+                let entries = self.batch.iter().flatten().map(|e| e.label.clone()).collect::<Vec<String>>();
+                format!("Drop {} queued commands{}?", self.group_digits(entries.len()), names(entries))
+            }
+            manager_database::RESET_STATS => format!(
+                "Reset {} reads, {} writes and {} errors?",
+                self.group_digits(self.stats.reads()),
+                self.group_digits(self.stats.writes()),
+                self.group_digits(self.stats.errors())
+            ),
+            _ => String::from("This removes saved state, continue?"),
+        }
+    }
+
+    pub fn is_confirmed(&self, option: &TerminalOption<Self>) -> bool {
+        option.is_confirmed()
+    }
+
     pub async fn valide_data_base_connection(&mut self) -> Result<(), ConnectException> {
         self.verify_database()?;
 
@@ -182,6 +302,41 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         Ok(())
     }
 
+    pub fn verify_selection(&self, level: ESelectionLevel) -> Result<(), ConnectException> {
+        match level {
+            ESelectionLevel::None => Ok(()),
+            ESelectionLevel::DataBase => self.verify_database(),
+            ESelectionLevel::Collection => self.verify_collection(),
+            ESelectionLevel::Element => self.verify_element(),
+        }
+    }
+
+    // A failed existence check is left to the command, which reports the
+    // backend error its own way.
+    pub async fn verify_existence(&mut self, level: ESelectionLevel) -> Result<(), ConnectException> {
+        if level >= ESelectionLevel::DataBase {
+            let data_base = self.data_base.clone().unwrap();
+            let query = DataBaseQuery::from_data_base(data_base.clone());
+            if !self.service.data_base_exists(query).await.unwrap_or(true) {
+                self.reset_database();
+                let message = format!("Selected data base '{}' no longer exists.", data_base);
+                return Err(ConnectException::new(message));
+            }
+        }
+
+        if level >= ESelectionLevel::Collection {
+            let collection = self.collection.clone().unwrap();
+            let query = DataBaseQuery::from(self.data_base.clone().unwrap(), collection.clone());
+            if !self.service.collection_exists(query).await.unwrap_or(true) {
+                self.reset_collection();
+                let message = format!("Selected collection '{}' no longer exists.", collection);
+                return Err(ConnectException::new(message));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn verify_element(&self) -> Result<(), ConnectException> {
         if self.element.is_none() {
             let exception = ConnectException::new(String::from("No element selected."));
@@ -206,6 +361,79 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         Ok(())
     }
 
+    pub fn path_fragments(&self, input: &str) -> Vec<String> {
+        input
+            .split(">")
+            .map(|f| String::from(f.trim()))
+            .filter(|f| !f.is_empty())
+            .collect::<Vec<String>>()
+    }
+
+    pub fn collection_target(&self, mut fragments: Vec<String>) -> Option<(String, String)> {
+        match fragments.len() {
+            1 => Some((self.data_base.clone()?, fragments.remove(0))),
+            2 => Some((fragments.remove(0), fragments.remove(0))),
+            _ => None,
+        }
+    }
+
+    pub fn page_bounds(&self, input: &str) -> Result<(usize, usize), String> {
+        let message = String::from("Invalid page, expected '<limit> [skip]' with positive numbers.");
+
+        let values = input
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<&str>>();
+        if values.is_empty() || values.len() > 2 {
+            return Err(message);
+        }
+
+        let limit = values[0].parse::<usize>().map_err(|_| message.clone())?;
+        if limit == 0 {
+            return Err(message);
+        }
+
+        let mut skip = 0;
+        if let Some(value) = values.get(1) {
+            skip = value.parse::<usize>().map_err(|_| message.clone())?;
+        }
+
+        Ok((limit, skip))
+    }
+
+    pub fn group_digits(&self, value: usize) -> String {
+        let separator = TerminalConfiguration::thousands_separator();
+        let digits = value.to_string();
+
+        let mut grouped = String::new();
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                grouped.push_str(&separator);
+            }
+            grouped.push(digit);
+        }
+
+        grouped
+    }
+
+    pub fn record_database_access(&mut self, data_base: String) {
+        self.data_base_history.insert(data_base, SystemTime::now());
+    }
+
+    pub fn elapsed_label(&self, time: &SystemTime) -> String {
+        let seconds = time.elapsed().map(|e| e.as_secs()).unwrap_or(0);
+        if seconds < 60 {
+            return format!("{}s", seconds);
+        }
+        if seconds < 3600 {
+            return format!("{}m", seconds / 60);
+        }
+        if seconds < 86400 {
+            return format!("{}h", seconds / 3600);
+        }
+        return format!("{}d", seconds / 86400);
+    }
+
     pub fn reset_database(&mut self) {
         self.data_base = None;
         self.reset_collection();
@@ -213,6 +441,8 @@ impl<T: IDBRepository> ManagerDatabase<T> {
 
     pub fn reset_collection(&mut self) {
         self.collection = None;
+        self.marked.clear();
+        self.page = None;
         self.reset_element();
     }
 
@@ -220,3 +450,135 @@ impl<T: IDBRepository> ManagerDatabase<T> {
         self.element = None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::manager::{
+        data_base::{
+            command::ESelectionLevel,
+            manager_database,
+            test_repository::{self, TestRepository},
+        },
+        i_manager::IManager,
+        terminal_manager,
+        terminal_option::TerminalOption,
+    };
+
+    #[test]
+    fn render_items_writes_every_item_of_a_large_result() {
+        let manager = TestRepository::new(Vec::new()).manager();
+
+        let count = 200_000;
+        let items = (0..count).map(|index| format!("{{\"_id\":\"{}\",\"payload\":\"{}\"}}", index, "x".repeat(64)));
+        let output = manager.render_items("Items:", items, " - ", "\n");
+
+        let plain = terminal_manager::strip_ansi(&output);
+        let lines = plain.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), count + 2);
+        assert_eq!(lines[0], "Items:");
+        assert_eq!(lines[1], "");
+        assert!(lines[2].starts_with(" - {\"_id\":\"0\""));
+        assert!(lines[count + 1].starts_with(&format!(" - {{\"_id\":\"{}\"", count - 1)));
+    }
+
+    #[test]
+    fn render_items_keeps_only_the_header_without_items() {
+        let manager = TestRepository::new(Vec::new()).manager();
+
+        let output = manager.render_items("Items:", Vec::<String>::new(), " - ", "\n");
+
+        assert_eq!(output, "Items:\n");
+    }
+
+    #[tokio::test]
+    async fn verify_existence_resets_a_dropped_data_base() {
+        let repository = TestRepository::new(Vec::new());
+        let mut manager = repository.manager();
+        manager.marked.insert(String::from("1"));
+
+        repository.remove_data_base();
+        let result = manager.verify_existence(ESelectionLevel::Collection).await;
+
+        let message = result.err().map(|error| error.message());
+        assert_eq!(message.as_deref(), Some("Selected data base 'shop' no longer exists."));
+        assert!(manager.data_base.is_none());
+        assert!(manager.collection.is_none());
+        assert!(manager.marked.is_empty());
+    }
+
+    #[tokio::test]
+    async fn verify_existence_resets_a_dropped_collection() {
+        let repository = TestRepository::new(Vec::new());
+        let mut manager = repository.manager();
+
+        repository.remove_collection();
+        let result = manager.verify_existence(ESelectionLevel::Collection).await;
+
+        let message = result.err().map(|error| error.message());
+        assert_eq!(message.as_deref(), Some("Selected collection 'orders' no longer exists."));
+        assert_eq!(manager.data_base.as_deref(), Some(test_repository::DATA_BASE));
+        assert!(manager.collection.is_none());
+    }
+
+    #[tokio::test]
+    async fn verify_existence_keeps_the_selection_on_backend_errors() {
+        let repository = TestRepository::new(Vec::new());
+        let mut manager = repository.manager();
+
+        repository.fail(true);
+        let result = manager.verify_existence(ESelectionLevel::Collection).await;
+
+        assert!(result.is_ok());
+        assert_eq!(manager.collection.as_deref(), Some(test_repository::COLLECTION));
+    }
+
+    #[tokio::test]
+    async fn commands_over_a_dropped_collection_report_it() {
+        let repository = TestRepository::new(Vec::new());
+        let manager = repository.manager();
+
+        repository.remove_collection();
+        let option = TerminalOption::from(String::new(), manager_database::SHOW_ELEMENTS, manager.clone());
+        let cursor = manager.manage(option).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Selected collection 'orders' no longer exists."));
+        assert!(cursor.manager().collection.is_none());
+        assert_eq!(cursor.manager().stats.errors(), 1);
+    }
+
+    #[tokio::test]
+    async fn commands_over_a_dropped_data_base_report_it() {
+        let repository = TestRepository::new(Vec::new());
+        let manager = repository.manager();
+
+        repository.remove_data_base();
+        let option = TerminalOption::from(String::new(), manager_database::SHOW_COLLECTIONS, manager.clone());
+        let cursor = manager.manage(option).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Selected data base 'shop' no longer exists."));
+        assert!(cursor.manager().data_base.is_none());
+        assert!(cursor.manager().collection.is_none());
+    }
+
+    #[tokio::test]
+    async fn typed_confirmation_text_does_not_skip_the_confirmation() {
+        let mut manager = TestRepository::new(Vec::new()).manager();
+        manager.saved_queries.insert(String::from("CONFIRMED"), String::from("shop > orders"));
+
+        let args = Vec::from(vec![String::from("CONFIRMED")]);
+        let option = TerminalOption::from_args(String::new(), manager_database::DELETE_SAVED, args, manager.clone());
+        let mut cursor = manager.manage(option).await;
+
+        let header = terminal_manager::strip_ansi(&cursor.header());
+        assert!(header.contains("Delete the saved query 'CONFIRMED': shop > orders?"));
+        assert!(cursor.manager().saved_queries.contains_key("CONFIRMED"));
+
+        let options = cursor.options();
+        assert!(!options[0].is_confirmed());
+        assert!(options[1].is_confirmed());
+        assert_eq!(options[1].args(), Vec::from(vec![String::from("CONFIRMED")]));
+    }
+
+}
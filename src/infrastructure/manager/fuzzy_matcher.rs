@@ -0,0 +1,65 @@
+const MATCH_SCORE: i64 = 1;
+const CONSECUTIVE_BONUS: i64 = 5;
+const BOUNDARY_BONUS: i64 = 3;
+const GAP_PENALTY: i64 = 1;
+
+pub struct FuzzyMatcher {
+}
+
+impl FuzzyMatcher {
+
+    pub fn score(pattern: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+        let pattern = pattern.to_lowercase().chars().collect::<Vec<char>>();
+        if pattern.is_empty() {
+            return None;
+        }
+
+        let characters = text.chars().collect::<Vec<char>>();
+
+        let mut score = 0;
+        let mut positions = Vec::<usize>::new();
+        let mut next = 0;
+        for (index, character) in characters.iter().enumerate() {
+            if next == pattern.len() {
+                break;
+            }
+            if !character.to_lowercase().eq(pattern[next].to_lowercase()) {
+                continue;
+            }
+
+            score = score + MATCH_SCORE;
+            match positions.last() {
+                Some(last) if *last + 1 == index => score = score + CONSECUTIVE_BONUS,
+                Some(last) => score = score - GAP_PENALTY * (index - last - 1) as i64,
+                None => score = score - GAP_PENALTY * index as i64,
+            }
+            if index == 0 || !characters[index - 1].is_alphanumeric() {
+                score = score + BOUNDARY_BONUS;
+            }
+
+            positions.push(index);
+            next = next + 1;
+        }
+
+        if next < pattern.len() {
+            return None;
+        }
+
+        Some((score, positions))
+    }
+
+    pub fn highlight(text: &str, positions: &[usize], start: &str, end: &str) -> String {
+        let mut highlighted = String::new();
+        for (index, character) in text.chars().enumerate() {
+            if positions.contains(&index) {
+                highlighted.push_str(start);
+                highlighted.push(character);
+                highlighted.push_str(end);
+            } else {
+                highlighted.push(character);
+            }
+        }
+        highlighted
+    }
+
+}
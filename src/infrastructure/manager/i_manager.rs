@@ -5,7 +5,22 @@ use super::{terminal_cursor::TerminalCursor, terminal_option::TerminalOption};
 #[async_trait]
 pub trait IManager: Clone + Send + Sync {
     fn text_input_option(&self) -> &str;
+    fn redraw_option(&self) -> &str {
+        ""
+    }
+    fn export_view_option(&self) -> &str {
+        ""
+    }
+    fn quick_switch_option(&self) -> &str {
+        ""
+    }
+    fn footer(&self) -> String {
+        String::new()
+    }
     async fn manage(&self, option: TerminalOption<Self>) -> TerminalCursor<Self> where Self: Sized;
+    async fn preview(&self, _option: TerminalOption<Self>) -> Option<String> where Self: Sized {
+        None
+    }
 }
 
 #[derive(Clone)]
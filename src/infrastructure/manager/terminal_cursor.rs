@@ -66,9 +66,21 @@ impl <T: IManager> TerminalCursor<T> {
         self
     }
 
+    pub fn focus(&mut self, index: usize) -> &Self {
+        if index < self.options.len() {
+            self.cursor = index;
+        }
+        self
+    }
+
     pub fn push(&mut self, option: TerminalOption<T>) -> &Self {
         self.options.push(option);
         self
     }
 
+    pub fn push_front(&mut self, option: TerminalOption<T>) -> &Self {
+        self.options.insert(0, option);
+        self
+    }
+
 }
\ No newline at end of file
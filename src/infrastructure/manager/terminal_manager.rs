@@ -1,8 +1,11 @@
-use std::io::{self, Write};
+use std::{fs, io::{self, Write}, time::Duration};
 
-use crossterm::event::{read, Event, KeyCode, KeyEventKind};
+use crossterm::{event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers}, terminal};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use super::{i_manager::IManager, terminal_cursor::TerminalCursor, terminal_option::TerminalOption};
+use crate::commons::configuration::{e_color_level::EColorLevel, terminal_configuration::{self, TerminalConfiguration}};
+
+use super::{fuzzy_matcher::FuzzyMatcher, i_manager::IManager, terminal_cursor::TerminalCursor, terminal_option::TerminalOption};
 
 pub(crate) const ANSI_RESET: &'static str = "\x1b[0m";
 pub(crate) const ANSI_BACKGROUND_WHITE: &'static str = "\x1b[47m";
@@ -11,16 +14,136 @@ pub(crate) const ANSI_BOLD: &'static str = "\x1b[1m";
 pub(crate) const ANSI_COLOR_RED: &'static str = "\x1b[31m";
 pub(crate) const ANSI_COLOR_GREEN: &'static str = "\x1b[32m";
 pub(crate) const ANSI_COLOR_YELLOW: &'static str = "\x1b[33m";
+pub(crate) const ANSI_COLOR_CYAN: &'static str = "\x1b[36m";
+
+const DEFAULT_WIDTH: usize = 80;
+const MIN_PAGE_LINES: usize = 5;
+const RESERVED_LINES: usize = 6;
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(250);
+const DETAIL_LIST_ROWS: usize = 10;
+const SIDE_PREVIEW_MIN_WIDTH: usize = 100;
+const PREVIEW_GUTTER: &'static str = " | ";
+
+pub(crate) fn terminal_width() -> usize {
+    terminal::size().map(|(columns, _)| columns as usize).unwrap_or(DEFAULT_WIDTH)
+}
+
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut plain = String::new();
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\x1b' {
+            plain.push(character);
+            continue;
+        }
+        if characters.peek() == Some(&'[') {
+            characters.next();
+            for sequence in characters.by_ref() {
+                if ('@'..='~').contains(&sequence) {
+                    break;
+                }
+            }
+        }
+    }
+    plain
+}
+
+pub(crate) fn copy_to_clipboard(text: &str) {
+    print!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    let _ = io::stdout().flush();
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let block = match chunk.len() {
+            1 => (chunk[0] as u32) << 16,
+            2 => (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8,
+            _ => (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32,
+        };
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let position = (block >> (18 - 6 * index)) & 0x3F;
+                encoded.push(ALPHABET[position as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+pub(crate) fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return String::from(text);
+    }
+
+    let limit = width.saturating_sub(3);
+    let mut used = 0;
+    let mut truncated = String::new();
+    for character in text.chars() {
+        let character_width = character.width().unwrap_or(0);
+        if used + character_width > limit {
+            break;
+        }
+        used = used + character_width;
+        truncated.push(character);
+    }
+
+    truncated.push_str("...");
+    truncated
+}
+
+pub(crate) fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+pub(crate) fn single_line(text: &str, width: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    truncate(&line, width)
+}
 
 #[derive(Clone)]
 pub struct TerminalManager<T: IManager> {
     cursor: TerminalCursor<T>,
+    scroll: usize,
+    page: usize,
+    preview: Option<String>,
+    sw_preview_pending: bool,
+    sw_detail: bool,
+    color: EColorLevel,
+    size: Option<(usize, usize)>,
+    notice: Option<String>,
+    last: Option<(String, String, Vec<String>)>,
+    sw_repeat_last: bool,
+    filter: Option<String>,
+    unfiltered: Option<TerminalCursor<T>>,
 }
 
 impl <T: IManager> TerminalManager<T> {
     
     pub fn new(cursor: TerminalCursor<T>) -> TerminalManager<T> {
-        return TerminalManager {cursor};
+        return TerminalManager {
+            cursor,
+            scroll: 0,
+            page: MIN_PAGE_LINES,
+            preview: None,
+            sw_preview_pending: true,
+            sw_detail: false,
+            color: EColorLevel::detect(),
+            size: terminal::size().ok().map(|(columns, rows)| (columns as usize, rows as usize)),
+            notice: None,
+            last: None,
+            sw_repeat_last: TerminalConfiguration::flag(terminal_configuration::REPEAT_LAST),
+            filter: None,
+            unfiltered: None,
+        };
     }
 
     pub async fn launch(&mut self) -> io::Result<()> {
@@ -31,24 +154,70 @@ impl <T: IManager> TerminalManager<T> {
             self.clear_screen();
             self.print(false);
 
+            if self.sw_preview_pending && !poll(PREVIEW_DEBOUNCE)? {
+                self.load_preview().await;
+                continue;
+            }
+
             let key_event = match read()? {
                 Event::Key(event) => event,
+                Event::Resize(columns, rows) => {
+                    self.size = Some((columns as usize, rows as usize));
+                    continue;
+                },
                 _ => continue, // Skip non-key events
             };
             
             if key_event.kind == KeyEventKind::Press {
+                self.notice = None;
                 match key_event.code {
-                    KeyCode::Up => {self.cursor.decrease();},
-                    KeyCode::Down => {self.cursor.increase();},
+                    KeyCode::Up => {
+                        self.cursor.decrease();
+                        self.reset_preview();
+                    },
+                    KeyCode::Down => {
+                        self.cursor.increase();
+                        self.reset_preview();
+                    },
+                    KeyCode::PageUp => {self.scroll = self.scroll.saturating_sub(self.page);},
+                    KeyCode::PageDown => {
+                        let lines = self.cursor.header().split("\n").count();
+                        self.scroll = (self.scroll + self.page).min(lines.saturating_sub(1));
+                    },
                     KeyCode::Enter => {
+                        if self.manage_view() {
+                            continue;
+                        }
 
                         let update = self.manage().await;
                         if update.is_none() {
-                            println!("Something goes wrong!");
-                            break;
+                            return Err(io::Error::other("Something goes wrong!"));
+                        }
+
+                        self.update_cursor(update.unwrap());
+                    },
+                    KeyCode::Char('l') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.purge_screen();
+                    },
+                    KeyCode::Char('e') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.export_view();
+                    },
+                    KeyCode::Tab => {
+                        let manager = self.cursor.manager();
+                        let option = String::from(manager.quick_switch_option());
+                        if !option.is_empty() {
+                            let mut option = TerminalOption::from(String::new(), &option, manager);
+                            let update = option.execute().await;
+                            self.update_cursor(update);
                         }
+                    },
+                    KeyCode::Char('/') => {
+                        self.clear_screen();
+                        self.print(true);
 
-                        self.cursor = update.unwrap();
+                        print!(" Filter: ");
+                        let input = self.keyboard_input();
+                        self.filter_options(input.trim());
                     },
                     KeyCode::Char('t') => {
                         self.clear_screen();
@@ -58,11 +227,10 @@ impl <T: IManager> TerminalManager<T> {
                         
                         let update: Option<TerminalCursor<T>> = self.manage_query(input).await;
                         if update.is_none() {
-                            println!("Something goes wrong!");
-                            break;
+                            return Err(io::Error::other("Something goes wrong!"));
                         }
 
-                        self.cursor = update.unwrap();
+                        self.update_cursor(update.unwrap());
                     }
                     KeyCode::Esc => {
                         println!("Exit");
@@ -95,6 +263,11 @@ impl <T: IManager> TerminalManager<T> {
         let _ = io::stdout().flush();
     }
     
+    fn purge_screen(&self) {
+        print!("\x1b[3J\x1b[2J\x1b[1;1H");
+        let _ = io::stdout().flush();
+    }
+
     fn hide_cursor(&self) {
         print!("\x1b[?25l");
         let _ = io::stdout().flush();
@@ -105,23 +278,299 @@ impl <T: IManager> TerminalManager<T> {
         let _ = io::stdout().flush();
     }
 
+    fn update_cursor(&mut self, cursor: TerminalCursor<T>) {
+        self.cursor = cursor;
+        self.sw_detail = false;
+        self.filter = None;
+        self.unfiltered = None;
+        self.scroll = 0;
+        self.reset_preview();
+    }
+
+    fn filter_options(&mut self, pattern: &str) {
+        let mut base = self.unfiltered.clone().unwrap_or(self.cursor.clone());
+        if pattern.is_empty() {
+            if self.unfiltered.is_some() {
+                self.update_cursor(base);
+            }
+            return;
+        }
+
+        let mut matches = base
+            .options()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, option)| {
+                FuzzyMatcher::score(pattern, &option.title()).map(|(score, _)| (score, index, option))
+            })
+            .collect::<Vec<(i64, usize, TerminalOption<T>)>>();
+
+        if matches.is_empty() {
+            self.notice = Some(format!("No options match '{}'.", pattern));
+            return;
+        }
+
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        let mut cursor = TerminalCursor::new(base.manager(), &base.header());
+        for (_, _, option) in matches {
+            cursor.push(option);
+        }
+
+        self.update_cursor(cursor);
+        self.filter = Some(String::from(pattern));
+        self.unfiltered = Some(base);
+    }
+
+    fn reset_preview(&mut self) {
+        self.preview = None;
+        self.sw_preview_pending = true;
+    }
+
+    async fn load_preview(&mut self) {
+        self.sw_preview_pending = false;
+        if let Some(option) = self.cursor.option().cloned() {
+            self.preview = self.cursor.manager().preview(option).await;
+            self.sw_detail = self.preview.is_some();
+        }
+    }
+
+    fn terminal_height(&self) -> usize {
+        self.size.map(|(_, rows)| rows).unwrap_or(usize::MAX)
+    }
+
+    fn terminal_width(&self) -> usize {
+        self.size.map(|(columns, _)| columns).unwrap_or(DEFAULT_WIDTH)
+    }
+
+    fn line_rows(&self, line: &str) -> usize {
+        let width = self.terminal_width().max(1);
+        let line_width = display_width(&strip_ansi(line));
+        line_width.div_ceil(width).max(1)
+    }
+
     fn print(&mut self, sw_ignore_focus: bool) {
-        print!("{}\n\n", self.cursor.header());
+        let mut view = self.render(sw_ignore_focus);
+        if !self.color.is_colored() {
+            view = strip_ansi(&view);
+        }
+
+        print!("{}", view);
+        let _ = io::stdout().flush();
+    }
+
+    fn render(&mut self, sw_ignore_focus: bool) -> String {
+        let mut view = self.render_header();
+
+        let sw_beside = self.preview.is_some() && self.terminal_width() >= SIDE_PREVIEW_MIN_WIDTH;
+        let list_width = if sw_beside {
+            self.terminal_width() / 2
+        } else {
+            self.terminal_width()
+        };
+
+        let list = self.render_options(sw_ignore_focus, list_width);
+        if let Some(preview) = &self.preview {
+            if sw_beside {
+                view.push_str(&self.render_beside(&list, preview, list_width));
+                view.push_str("\n");
+            } else {
+                view.push_str(&list);
+                view.push_str("\n");
+                view.push_str(&format!("{}Preview:{}\n{}\n\n", ANSI_BOLD, ANSI_RESET, preview));
+            }
+        } else {
+            view.push_str(&list);
+            view.push_str("\n");
+        }
 
-        for cursor in self.cursor.options().iter_mut().enumerate() {
+        let footer = self.cursor.manager().footer();
+        if !footer.is_empty() {
+            view.push_str(&format!("{}\n\n", footer));
+        }
+
+        if let Some(notice) = &self.notice {
+            view.push_str(&format!("{}{}{}\n\n", ANSI_COLOR_YELLOW, notice, ANSI_RESET));
+        }
+
+        view
+    }
+
+    fn render_options(&mut self, sw_ignore_focus: bool, width: usize) -> String {
+        let mut view = String::new();
+
+        let options = self.cursor.options();
+        let (first, last) = self.options_window(&options);
+        if first > 0 {
+            view.push_str(&format!("   ... {} above\n", first));
+        }
+
+        for cursor in options.iter().enumerate().take(last).skip(first) {
             let index = cursor.0;
             let position = cursor.1;
 
-            let mut title = position.title();
-            if !sw_ignore_focus && position.is_focused() {
+            let prefix = format!("{}.- ", index + 1);
+            let width = width.saturating_sub(prefix.len() + 1);
+
+            let mut title = truncate(&position.title(), width);
+            let sw_focused = !sw_ignore_focus && position.is_focused();
+            if let Some(filter) = &self.filter {
+                if let Some((_, positions)) = FuzzyMatcher::score(filter, &title) {
+                    let mut end = String::from(ANSI_RESET);
+                    if sw_focused {
+                        end.push_str(ANSI_BACKGROUND_WHITE);
+                    }
+                    let start = format!("{}{}", ANSI_BOLD, ANSI_COLOR_CYAN);
+                    title = FuzzyMatcher::highlight(&title, &positions, &start, &end);
+                }
+            }
+            if sw_focused {
                 title = format!("{}{}{}", ANSI_BACKGROUND_WHITE, title, ANSI_RESET);
+                if !self.color.is_colored() {
+                    title = format!("> {}", title);
+                }
+            }
+            view.push_str(&format!("{}{}.\n", prefix, title));
+            if position.is_separated() {
+                view.push_str("\n");
             }
-            print!("{}.- {}.\n", index + 1, title);
         }
 
-        print!("\n");
+        if last < options.len() {
+            view.push_str(&format!("   ... {} below\n", options.len() - last));
+        }
 
-        let _ = io::stdout().flush();
+        view
+    }
+
+    // Lays the options and the preview out as two columns, the preview lines
+    // are cut to the space left by the list.
+    fn render_beside(&self, list: &str, preview: &str, list_width: usize) -> String {
+        let preview_width = self.terminal_width().saturating_sub(list_width + PREVIEW_GUTTER.len());
+
+        let mut left = list.lines().collect::<Vec<&str>>();
+        let mut right = Vec::from(vec![format!("{}Preview:{}", ANSI_BOLD, ANSI_RESET)]);
+        for line in preview.lines() {
+            let plain = strip_ansi(line);
+            if display_width(&plain) <= preview_width {
+                right.push(String::from(line));
+            } else {
+                right.push(truncate(&plain, preview_width));
+            }
+        }
+
+        while left.len() < right.len() {
+            left.push("");
+        }
+
+        let mut view = String::new();
+        for (index, line) in left.iter().enumerate() {
+            let padding = list_width.saturating_sub(display_width(&strip_ansi(line)));
+            let detail = right.get(index).map(|l| l.as_str()).unwrap_or_default();
+            view.push_str(format!("{}{}{}{}", line, " ".repeat(padding), PREVIEW_GUTTER, detail).trim_end());
+            view.push_str("\n");
+        }
+
+        view
+    }
+
+    fn options_window(&self, options: &[TerminalOption<T>]) -> (usize, usize) {
+        if !self.sw_detail || options.len() <= DETAIL_LIST_ROWS {
+            return (0, options.len());
+        }
+
+        let focus = options.iter().position(|o| o.is_focused()).unwrap_or(0);
+        let first = focus
+            .saturating_sub(DETAIL_LIST_ROWS / 2)
+            .min(options.len() - DETAIL_LIST_ROWS);
+        (first, first + DETAIL_LIST_ROWS)
+    }
+
+    fn render_header(&mut self) -> String {
+        let header = self.cursor.header();
+        let lines = header.split("\n").collect::<Vec<&str>>();
+        let rows = lines.iter().map(|l| self.line_rows(l)).collect::<Vec<usize>>();
+
+        let footer = self.cursor.manager().footer();
+        let reserved = self.cursor.options().len() + footer.split("\n").count() + RESERVED_LINES;
+        let page_rows = self.terminal_height().saturating_sub(reserved).max(MIN_PAGE_LINES);
+
+        if rows.iter().sum::<usize>() <= page_rows {
+            self.page = lines.len().max(1);
+            return format!("{}\n\n", header);
+        }
+
+        let mut last = lines.len();
+        let mut used = 0;
+        while last > 0 && used + rows[last - 1] <= page_rows {
+            used = used + rows[last - 1];
+            last = last - 1;
+        }
+        self.scroll = self.scroll.min(last).min(lines.len().saturating_sub(1));
+
+        let mut end = self.scroll;
+        let mut used = 0;
+        while end < lines.len() && used + rows[end] <= page_rows {
+            used = used + rows[end];
+            end = end + 1;
+        }
+        if end == self.scroll {
+            end = end + 1;
+        }
+        self.page = end - self.scroll;
+
+        let mut view = format!("{}{}\n\n", lines[self.scroll..end].join("\n"), ANSI_RESET);
+        view.push_str(&format!(
+            "{}-- Lines {}-{} of {}, use PgUp/PgDn to scroll --{}\n\n",
+            ANSI_BOLD,
+            self.scroll + 1,
+            end,
+            lines.len(),
+            ANSI_RESET
+        ));
+
+        view
+    }
+
+    fn export_view(&mut self) {
+        let view = strip_ansi(&self.render(true));
+
+        print!(" Export path: ");
+        let input = self.keyboard_input();
+        let path = input.trim();
+
+        let notice = if path.is_empty() {
+            String::from("Export cancelled, no path given.")
+        } else {
+            match fs::write(path, view) {
+                Ok(()) => format!("View exported to '{}'.", path),
+                Err(error) => format!("Cannot export view to '{}': {}", path, error),
+            }
+        };
+
+        self.notice = Some(notice);
+    }
+
+    // Redraw and export act on the screen only, the cursor and its
+    // navigation state are kept as they are.
+    fn manage_view(&mut self) -> bool {
+        let option = match self.cursor.option() {
+            Some(option) => option.option(),
+            None => return false,
+        };
+
+        let manager = self.cursor.manager();
+        if option == manager.redraw_option() {
+            self.purge_screen();
+            return true;
+        }
+
+        if option == manager.export_view_option() {
+            self.export_view();
+            return true;
+        }
+
+        false
     }
 
     async fn manage(&mut self) -> Option<TerminalCursor<T>> {
@@ -130,21 +579,91 @@ impl <T: IManager> TerminalManager<T> {
             let mut option = o_option.unwrap();
             if option.input_required() {
                 let input = self.keyboard_input();
+                if let Some(mut last) = self.repeat_last(&input) {
+                    println!("\n Please stand by...");
+                    return Some(last.execute().await);
+                }
                 option.push_arg(input);
             }
 
             println!("\n Please stand by...");
 
+            self.remember(&option);
             return Some(option.execute().await);
         }
         None
     }
 
     async fn manage_query(&mut self, query: String) -> Option<TerminalCursor<T>> {
+        if let Some(mut last) = self.repeat_last(&query) {
+            return Some(last.execute().await);
+        }
+
         let manager = self.cursor.manager();
         let args = Vec::from(vec![query.clone()]);
         let mut option = TerminalOption::from_input(args, manager);
+        self.remember(&option);
         return Some(option.execute().await);
     }
 
-}
\ No newline at end of file
+    // The confirmation is not kept, repeating a confirmed command asks for
+    // it again over the current selection.
+    fn remember(&mut self, option: &TerminalOption<T>) {
+        self.last = Some((option.title(), option.option(), option.args()));
+    }
+
+    fn repeat_last(&self, input: &str) -> Option<TerminalOption<T>> {
+        if !self.sw_repeat_last || !input.trim().is_empty() {
+            return None;
+        }
+
+        let (title, option, args) = self.last.clone()?;
+        let manager = self.cursor.manager();
+        Some(TerminalOption::from_args(title, &option, args, manager))
+    }
+
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_counts_wide_characters_twice() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("a😀b"), 4);
+    }
+
+    #[test]
+    fn truncate_keeps_text_that_fits() {
+        assert_eq!(truncate("日本", 4), "日本");
+        assert_eq!(truncate("a😀b", 4), "a😀b");
+    }
+
+    #[test]
+    fn truncate_never_splits_a_wide_character() {
+        assert_eq!(truncate("日本語テキスト", 8), "日本...");
+        assert_eq!(truncate("ab日本", 5), "ab...");
+        assert_eq!(truncate("😀😀😀😀", 6), "😀...");
+    }
+
+    #[test]
+    fn truncate_fits_the_ellipsis_in_the_width() {
+        let text = "mixed 日本語 and 😀 emoji text";
+        for width in 3..display_width(text) {
+            let truncated = truncate(text, width);
+            assert!(truncated.ends_with("..."));
+            assert!(display_width(&truncated) <= width);
+            assert!(display_width(&truncated) >= width - 1);
+        }
+    }
+
+    #[test]
+    fn pad_fills_up_to_the_display_width() {
+        assert_eq!(pad("ab", 4), "ab  ");
+        assert_eq!(pad("日本", 6), "日本  ");
+        assert_eq!(display_width(&pad("😀", 5)), 5);
+        assert_eq!(pad("日本語", 4), "日本語");
+    }
+
+}
@@ -9,7 +9,9 @@ pub struct TerminalOption<T: IManager> {
     title: String,
     args: Vec<String>,
     manager: T,
-    require_input: bool
+    require_input: bool,
+    separated: bool,
+    confirmed: bool
 }
 
 impl <T: IManager> TerminalOption<T> {
@@ -31,7 +33,9 @@ impl <T: IManager> TerminalOption<T> {
             title: title,
             args: args,
             manager: manager,
-            require_input: false
+            require_input: false,
+            separated: false,
+            confirmed: false
         }
     }
 
@@ -84,6 +88,24 @@ impl <T: IManager> TerminalOption<T> {
         self
     }
 
+    pub fn is_separated(&self) -> bool {
+        self.separated
+    }
+
+    pub fn separated(&mut self) -> &Self {
+        self.separated = true;
+        self
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed
+    }
+
+    pub fn confirmed(&mut self) -> &Self {
+        self.confirmed = true;
+        self
+    }
+
     pub fn require_input_ref(&mut self) -> Self {
         self.require_input().clone()
     }
@@ -1,10 +1,33 @@
+pub mod commons {
+    pub mod build_info;
+    pub mod configuration {
+        pub mod e_color_level;
+        pub mod terminal_configuration;
+    }
+    pub mod connection_uri;
+    pub mod e_exit_code;
+    pub mod e_output_format;
+    pub mod launch_arguments;
+    pub mod password_reader;
+    pub mod saved_queries;
+    pub mod ssh_tunnel;
+}
 pub mod infrastructure {
     pub mod manager {
         pub mod data_base {
+            pub mod batch_entry;
+            pub mod command;
+            pub mod command_registry;
             pub mod manager_database;
             pub mod path_interpeter;
+            pub mod schema;
+            pub mod session_stats;
+            #[cfg(test)]
+            pub mod test_repository;
+            pub mod tree_view;
             pub mod utils;
         }
+        pub mod fuzzy_matcher;
         pub mod i_manager;
         pub mod terminal_cursor;
         pub mod terminal_manager;